@@ -26,7 +26,7 @@
 //! ### Interactive Elements
 //! - Click to select nodes
 //! - Double-click to rename
-//! - Drag nodes to reorder or reparent
+//! - Drag nodes to reorder or reparent (hold Ctrl/Cmd to copy them)
 //! - Click action icons for state changes
 //! - Expand/collapse collections with arrow icons
 //!
@@ -36,7 +36,8 @@
 //! ```
 
 use egui_arbor::{
-    ActionIcon, DropPosition, IconType, Outliner, OutlinerActions, OutlinerNode,
+    tree_ops::{self, TreeOperations},
+    ActionIcon, DropOperation, DropPosition, IconType, Outliner, OutlinerActions, OutlinerNode,
 };
 use std::collections::{HashSet, VecDeque};
 use std::time::SystemTime;
@@ -154,6 +155,11 @@ impl TreeNode {
         false
     }
 
+    /// Returns the largest ID in this subtree
+    fn max_id(&self) -> u64 {
+        self.children.iter().map(Self::max_id).fold(self.id, u64::max)
+    }
+
     /// Remove a node by ID and return it if found
     fn remove_node(&mut self, id: u64) -> Option<TreeNode> {
         for i in 0..self.children.len() {
@@ -203,6 +209,8 @@ impl TreeNode {
                             return true;
                         }
                     }
+                    // Appending at the root level is handled by the caller
+                    _ => return false,
                 }
             }
             if self.children[i].insert_node(target_id, node.clone(), position) {
@@ -270,6 +278,14 @@ impl OutlinerNode for TreeNode {
     }
 }
 
+/// Lets `tree_ops::copy_into_roots` give copied nodes fresh IDs
+impl TreeOperations for TreeNode {
+    fn set_id(&mut self, id: u64) -> bool {
+        self.id = id;
+        true
+    }
+}
+
 /// Event log entry for tracking user interactions.
 #[derive(Clone, Debug)]
 struct LogEntry {
@@ -391,6 +407,24 @@ impl OutlinerActions<TreeNode> for TreeActions {
         );
     }
 
+    /// Called when nodes are dropped with Ctrl/Cmd held.
+    /// The copies are made in the app's update method.
+    fn on_copy(&mut self, ids: &[u64], target: &u64, position: DropPosition) {
+        self.log_event(
+            format!("Copy: nodes {:?} → target {} ({:?})", ids, target, position),
+            EventType::DragDrop,
+        );
+    }
+
+    /// Called when nodes are dropped with Alt held.
+    /// This example has no linked nodes, so the drop is only logged.
+    fn on_link(&mut self, ids: &[u64], target: &u64, position: DropPosition) {
+        self.log_event(
+            format!("Link: nodes {:?} → target {} ({:?}), not supported here", ids, target, position),
+            EventType::DragDrop,
+        );
+    }
+
     /// Called when a node's selection state changes.
     /// This example implements multi-selection behavior.
     fn on_select(&mut self, id: &u64, selected: bool) {
@@ -658,6 +692,7 @@ impl eframe::App for ExampleApp {
                     ui.label("• Drop Before target");
                     ui.label("• Drop After target");
                     ui.label("• Drop Inside collections");
+                    ui.label("• Hold Ctrl/Cmd to copy");
                     ui.add_space(8.0);
                     
                     ui.label(egui::RichText::new("Visual Indicators:").strong());
@@ -801,50 +836,75 @@ impl eframe::App for ExampleApp {
             // Handle drag-drop events
             // When a user drags a node and drops it on a target, this callback fires
             if let Some(drop_event) = response.drop_event() {
-                let target_id = &drop_event.target;
+                let target_id = drop_event.target;
                 let position = drop_event.position;
 
                 // Get all nodes being dragged (primary + selected)
                 let dragging_ids = response.dragging_nodes();
                 
-                if !dragging_ids.is_empty() {
-                    // Step 1: Remove all dragging nodes from their current locations
-                    let mut removed_nodes = Vec::new();
-                    for drag_id in dragging_ids {
-                        for root in &mut self.tree {
-                            if let Some(node) = root.remove_node(*drag_id) {
-                                removed_nodes.push(node);
-                                break;
-                            }
-                        }
+                match drop_event.operation {
+                    // Ctrl/Cmd leaves the originals in place and inserts copies with fresh IDs
+                    DropOperation::Copy => {
+                        let mut next_id = self.tree.iter().map(TreeNode::max_id).max().map_or(0, |id| id + 1);
+                        let copied = tree_ops::copy_into_roots(&mut self.tree, dragging_ids, target_id.as_ref(), position, &mut |_| {
+                            let id = next_id;
+                            next_id += 1;
+                            id
+                        });
+                        self.actions.log_event(
+                            format!("✓ Copied {} node(s) to target {:?} ({:?})", copied.len(), target_id, position),
+                            EventType::DragDrop,
+                        );
                     }
+                    // Links are only logged by `on_link`
+                    DropOperation::Link => {}
+                    DropOperation::Move => {
+                        if !dragging_ids.is_empty() {
+                            // Step 1: Remove all dragging nodes from their current locations
+                            let mut removed_nodes = Vec::new();
+                            for drag_id in dragging_ids {
+                                for root in &mut self.tree {
+                                    if let Some(node) = root.remove_node(*drag_id) {
+                                        removed_nodes.push(node);
+                                        break;
+                                    }
+                                }
+                            }
 
-                    // Step 2: Insert all nodes at the target position
-                    let mut all_inserted = true;
-                    for node in removed_nodes {
-                        let mut inserted = false;
-                        for root in &mut self.tree {
-                            if root.insert_node(*target_id, node.clone(), position) {
-                                inserted = true;
-                                break;
+                            // Step 2: Insert all nodes at the target position
+                            let mut all_inserted = true;
+                            for node in removed_nodes {
+                                // Dropped into the empty space below the tree, which has no target
+                                let Some(target_id) = target_id else {
+                                    self.tree.push(node);
+                                    continue;
+                                };
+
+                                let mut inserted = false;
+                                for root in &mut self.tree {
+                                    if root.insert_node(target_id, node.clone(), position) {
+                                        inserted = true;
+                                        break;
+                                    }
+                                }
+                                if !inserted {
+                                    all_inserted = false;
+                                }
                             }
-                        }
-                        if !inserted {
-                            all_inserted = false;
-                        }
-                    }
                     
-                    if all_inserted {
-                        self.actions.log_event(
-                            format!("✓ Successfully moved {} node(s) to target {} ({:?})",
-                                dragging_ids.len(), target_id, position),
-                            EventType::DragDrop,
-                        );
-                    } else {
-                        self.actions.log_event(
-                            format!("✗ Failed to move some nodes to target {}", target_id),
-                            EventType::DragDrop,
-                        );
+                            if all_inserted {
+                                self.actions.log_event(
+                                    format!("✓ Successfully moved {} node(s) to target {:?} ({:?})",
+                                        dragging_ids.len(), target_id, position),
+                                    EventType::DragDrop,
+                                );
+                            } else {
+                                self.actions.log_event(
+                                    format!("✗ Failed to move some nodes to target {:?}", target_id),
+                                    EventType::DragDrop,
+                                );
+                            }
+                        }
                     }
                 }
             }
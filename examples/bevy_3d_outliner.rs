@@ -5,6 +5,7 @@
 //! - 3D scene with collections and objects (cubes, cylinders, cones)
 //! - Tree outliner synchronized with 3D scene visibility
 //! - Drag and drop to reorganize scene hierarchy
+//! - Drag objects from the outliner into the viewport to place instances
 //! - Orbit camera controls with mouse
 //! - Three collections with different colored objects
 //!
@@ -13,12 +14,13 @@
 //! - Right mouse: Pan camera
 //! - Scroll: Zoom camera
 //! - Drag nodes in outliner to reorganize hierarchy
+//! - Drop objects onto the viewport to place instances of them
 //! - Click visibility icons in outliner to show/hide objects
 
 use bevy::prelude::*;
 use egui_arbor::{
-    tree_ops::TreeOperations, ActionIcon, DropPosition, IconType, Outliner, OutlinerActions,
-    OutlinerNode,
+    tree_ops::{self, TreeOperations}, ActionIcon, DragPayload, DropOperation, DropPosition, IconType,
+    Outliner, OutlinerActions, OutlinerNode,
 };
 use std::collections::HashSet;
 
@@ -37,8 +39,19 @@ fn main() {
         })
         .init_resource::<SceneTree>()
         .init_resource::<TreeActions>()
+        .init_resource::<ViewportDrops>()
+        .init_resource::<CopiedObjects>()
         .add_systems(Startup, setup_scene)
-        .add_systems(Update, (ui_system, orbit_camera_system, sync_visibility_system))
+        .add_systems(
+            Update,
+            (
+                ui_system,
+                place_dropped_instances_system.after(ui_system),
+                spawn_copied_objects_system.after(ui_system),
+                orbit_camera_system,
+                sync_visibility_system,
+            ),
+        )
         .run();
 }
 
@@ -107,6 +120,11 @@ impl TreeNode {
         
         false
     }
+
+    /// Returns the largest ID in this subtree
+    fn max_id(&self) -> u64 {
+        self.children.iter().map(Self::max_id).fold(self.id, u64::max)
+    }
 }
 
 impl OutlinerNode for TreeNode {
@@ -146,7 +164,13 @@ impl OutlinerNode for TreeNode {
 }
 
 /// Implement TreeOperations to get drag-drop functionality
-impl TreeOperations for TreeNode {}
+impl TreeOperations for TreeNode {
+    /// Lets `tree_ops::copy_into_roots` give copied nodes fresh IDs
+    fn set_id(&mut self, id: u64) -> bool {
+        self.id = id;
+        true
+    }
+}
 
 /// Resource holding the scene tree structure
 #[derive(Resource)]
@@ -190,6 +214,21 @@ impl Default for SceneTree {
     }
 }
 
+impl SceneTree {
+    /// Returns an ID that no node uses yet
+    fn next_id(&self) -> u64 {
+        self.nodes.iter().map(TreeNode::max_id).max().map_or(0, |id| id + 1)
+    }
+}
+
+/// Nodes dropped from the outliner onto the viewport, with the pointer position
+#[derive(Resource, Default)]
+struct ViewportDrops(Vec<(Vec<u64>, Vec2)>);
+
+/// Nodes copied in the outliner, as (original ID, copy ID) pairs
+#[derive(Resource, Default)]
+struct CopiedObjects(Vec<(u64, u64)>);
+
 /// Actions handler for the outliner
 #[derive(Resource)]
 struct TreeActions {
@@ -405,18 +444,22 @@ fn ui_system(
     mut contexts: bevy_egui::EguiContexts,
     mut scene_tree: ResMut<SceneTree>,
     mut actions: ResMut<TreeActions>,
+    mut viewport_drops: ResMut<ViewportDrops>,
+    mut copied_objects: ResMut<CopiedObjects>,
 ) {
     let ctx = contexts.ctx_mut();
 
-    bevy_egui::egui::SidePanel::left("outliner_panel")
+    let panel = bevy_egui::egui::SidePanel::left("outliner_panel")
         .default_width(300.0)
         .show(ctx, |ui| {
             ui.heading("🌳 Scene Outliner");
             ui.separator();
 
             ui.label("Drag and drop to reorganize");
+            ui.label("Hold Ctrl/Cmd while dropping to copy");
             ui.label("Click the eye icon to toggle visibility");
             ui.label("Double-click to rename");
+            ui.label("Drop objects onto the viewport to place instances");
             ui.add_space(8.0);
 
             let response = Outliner::new("scene_outliner").show(ui, &scene_tree.nodes, &mut *actions);
@@ -433,41 +476,144 @@ fn ui_system(
 
             // Handle drag-drop events
             if let Some(drop_event) = response.drop_event() {
-                let target_id = &drop_event.target;
+                let target_id = drop_event.target.as_ref();
                 let position = drop_event.position;
 
                 // Get all nodes being dragged (primary + selected)
                 let dragging_ids = response.dragging_nodes();
                 
-                if !dragging_ids.is_empty() {
-                    // Step 1: Remove all dragging nodes from their current locations
-                    let mut removed_nodes = Vec::new();
-                    for drag_id in dragging_ids {
-                        for root in &mut scene_tree.nodes {
-                            if let Some(node) = root.remove_node(drag_id) {
-                                removed_nodes.push(node);
-                                break;
-                            }
-                        }
+                match drop_event.operation {
+                    // Ctrl/Cmd leaves the originals in place and inserts copies with fresh IDs
+                    DropOperation::Copy => {
+                        let mut next_id = scene_tree.next_id();
+                        let mut copies = Vec::new();
+                        tree_ops::copy_into_roots(&mut scene_tree.nodes, dragging_ids, target_id, position, &mut |original: &TreeNode| {
+                            let id = next_id;
+                            next_id += 1;
+                            copies.push((original.id, id));
+                            id
+                        });
+                        copied_objects.0.extend(copies);
                     }
+                    // This scene has no linked objects
+                    DropOperation::Link => eprintln!("Linking nodes is not supported in this example"),
+                    DropOperation::Move => {
+                        if !dragging_ids.is_empty() {
+                            // Step 1: Remove all dragging nodes from their current locations
+                            let mut removed_nodes = Vec::new();
+                            for drag_id in dragging_ids {
+                                for root in &mut scene_tree.nodes {
+                                    if let Some(node) = root.remove_node(drag_id) {
+                                        removed_nodes.push(node);
+                                        break;
+                                    }
+                                }
+                            }
 
-                    // Step 2: Insert all nodes at the target position
-                    for node in removed_nodes {
-                        let mut inserted = false;
-                        for root in &mut scene_tree.nodes {
-                            if root.insert_node(target_id, node.clone(), position) {
-                                inserted = true;
-                                break;
+                            // Step 2: Insert all nodes at the target position
+                            for node in removed_nodes {
+                                // Drops below the tree have no target and append at the root level
+                                let Some(target_id) = target_id else {
+                                    scene_tree.nodes.push(node);
+                                    continue;
+                                };
+                                let inserted = tree_ops::insert_into_roots(&mut scene_tree.nodes, target_id, node.clone(), position);
+                                if !inserted {
+                                    // If insertion failed, log it (in a real app you might want to restore the node)
+                                    eprintln!("Failed to insert node {} at target {}", node.id, target_id);
+                                }
                             }
                         }
-                        if !inserted {
-                            // If insertion failed, log it (in a real app you might want to restore the node)
-                            eprintln!("Failed to insert node {} at target {}", node.id, target_id);
-                        }
                     }
                 }
             }
+
+            response
+                .dropped_outside()
+                .map(|(nodes, pointer)| (nodes.to_vec(), pointer))
         });
+
+    // Nodes released outside the panel landed on the viewport
+    if let Some((nodes, pointer)) = panel.inner
+        && !panel.response.rect.contains(pointer)
+    {
+        viewport_drops.0.push((nodes, Vec2::new(pointer.x, pointer.y)));
+    }
+}
+
+/// Places instances of the objects dropped onto the viewport where the
+/// pointer ray hits the ground
+fn place_dropped_instances_system(
+    mut commands: Commands,
+    mut viewport_drops: ResMut<ViewportDrops>,
+    mut scene_tree: ResMut<SceneTree>,
+    mut actions: ResMut<TreeActions>,
+    objects: Query<(&SceneObject, &Mesh3d, &MeshMaterial3d<StandardMaterial>)>,
+    camera: Query<(&Camera, &GlobalTransform), With<OrbitCamera>>,
+) {
+    let Ok((camera, camera_transform)) = camera.single() else {
+        return;
+    };
+
+    for (ids, pointer) in viewport_drops.0.drain(..) {
+        let Ok(ray) = camera.viewport_to_world(camera_transform, pointer) else {
+            continue;
+        };
+        let Some(distance) = ray.intersect_plane(Vec3::ZERO, InfinitePlane3d::new(Vec3::Y)) else {
+            continue;
+        };
+        let point = ray.get_point(distance);
+
+        for (offset, id) in ids.iter().enumerate() {
+            // Collections have no mesh to instance
+            let Some((_, mesh, material)) = objects.iter().find(|(object, ..)| object.id == *id) else {
+                continue;
+            };
+            let Some(name) = tree_ops::find_in_roots(&scene_tree.nodes, id).map(|node| format!("{} Instance", node.name)) else {
+                continue;
+            };
+
+            // Spread multiple dropped objects out along the X axis
+            let instance_id = scene_tree.next_id();
+            commands.spawn((
+                mesh.clone(),
+                material.clone(),
+                Transform::from_translation(point + Vec3::new(offset as f32 * 1.5, 0.5, 0.0)),
+                SceneObject { id: instance_id },
+            ));
+
+            // List the instance next to its original in the outliner
+            tree_ops::insert_into_roots(&mut scene_tree.nodes, id, TreeNode::entity(instance_id, name), DropPosition::After);
+            actions.visible.insert(instance_id);
+        }
+    }
+}
+
+/// Spawns the objects copied in the outliner next to their originals
+fn spawn_copied_objects_system(
+    mut commands: Commands,
+    mut copied_objects: ResMut<CopiedObjects>,
+    scene_tree: Res<SceneTree>,
+    mut actions: ResMut<TreeActions>,
+    objects: Query<(&SceneObject, &Mesh3d, &MeshMaterial3d<StandardMaterial>, &Transform)>,
+) {
+    for (original, copy) in copied_objects.0.drain(..) {
+        // Copies that couldn't be placed aren't in the tree
+        if tree_ops::find_in_roots(&scene_tree.nodes, &copy).is_none() {
+            continue;
+        }
+        if actions.is_visible(&original) {
+            actions.visible.insert(copy);
+        }
+
+        // Collections have no mesh to copy
+        let Some((_, mesh, material, transform)) = objects.iter().find(|(object, ..)| object.id == original) else {
+            continue;
+        };
+        let mut transform = *transform;
+        transform.translation.z += 1.5;
+        commands.spawn((mesh.clone(), material.clone(), transform, SceneObject { id: copy }));
+    }
 }
 
 /// Orbit camera system with mouse controls
@@ -476,17 +622,22 @@ fn orbit_camera_system(
     mouse_button_input: Res<ButtonInput<MouseButton>>,
     mut mouse_motion: EventReader<bevy::input::mouse::MouseMotion>,
     mut mouse_wheel: EventReader<bevy::input::mouse::MouseWheel>,
+    mut contexts: bevy_egui::EguiContexts,
 ) {
     let Ok((mut transform, mut orbit)) = query.single_mut() else {
         return;
     };
 
+    // Dragging nodes out of the outliner shouldn't orbit the camera
+    let dragging_nodes =
+        bevy_egui::egui::DragAndDrop::has_payload_of_type::<DragPayload<u64>>(contexts.ctx_mut());
+
     let mut rotation_move = Vec2::ZERO;
     let mut scroll = 0.0;
     let mut pan_move = Vec2::ZERO;
 
     // Handle mouse input
-    if mouse_button_input.pressed(MouseButton::Left) {
+    if mouse_button_input.pressed(MouseButton::Left) && !dragging_nodes {
         for ev in mouse_motion.read() {
             rotation_move += ev.delta;
         }
@@ -0,0 +1,641 @@
+//! Cut, copy and paste of outliner nodes.
+//!
+//! This module provides [`NodeClipboard`], a typed clipboard holding deep copies of
+//! nodes. The outliner reports clipboard shortcuts as a
+//! [`ClipboardCommand`](crate::response::ClipboardCommand) in its response; applying
+//! that command with [`NodeClipboard::apply`] keeps the clipboard in egui's memory,
+//! so nodes can be pasted within one outliner or across outliners showing the same
+//! node type.
+//!
+//! With the `serde` feature the clipboard can also be converted to and from text,
+//! for exchanging nodes through the system clipboard;
+//! [`NodeClipboard::apply_with_system_clipboard`] does this for outliner commands.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! let response = Outliner::new("tree").show(ui, &tree, &mut actions);
+//!
+//! if let Some(command) = response.clipboard_command() {
+//!     let mut next_id = || { counter += 1; counter };
+//!     NodeClipboard::apply(ui.ctx(), &mut tree, command, &mut |_| next_id());
+//! }
+//! ```
+
+use crate::response::ClipboardCommand;
+use crate::traits::DropPosition;
+use crate::tree_ops::{
+    find_in_roots, insert_into_roots, reassign_copy, remove_from_roots, DuplicateOptions,
+    TreeOperations,
+};
+use std::collections::HashSet;
+
+/// Whether the clipboard contents were copied or cut.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ClipboardMode {
+    /// The nodes were copied; every paste inserts copies with fresh IDs.
+    #[default]
+    Copy,
+
+    /// The nodes were cut; the first paste moves them back in with their
+    /// original IDs, later pastes insert copies.
+    Cut,
+}
+
+/// A typed clipboard holding deep copies of outliner nodes.
+///
+/// # Type Parameters
+///
+/// * `N` - The node type. Pasting requires [`TreeOperations`], including
+///   [`TreeOperations::set_id`] so copies can receive fresh IDs.
+///
+/// # Examples
+///
+/// ```
+/// use egui_arbor::{OutlinerNode, DropPosition, tree_ops::TreeOperations};
+/// use egui_arbor::clipboard::NodeClipboard;
+///
+/// #[derive(Clone)]
+/// struct Node { id: u64, name: String, children: Vec<Node> }
+///
+/// impl OutlinerNode for Node {
+///     type Id = u64;
+///     fn id(&self) -> Self::Id { self.id }
+///     fn name(&self) -> &str { &self.name }
+///     fn is_collection(&self) -> bool { true }
+///     fn children(&self) -> &[Self] { &self.children }
+///     fn children_mut(&mut self) -> &mut Vec<Self> { &mut self.children }
+/// }
+///
+/// impl TreeOperations for Node {
+///     fn set_id(&mut self, id: u64) -> bool { self.id = id; true }
+/// }
+///
+/// let mut tree = vec![
+///     Node { id: 1, name: "Cube".into(), children: vec![] },
+///     Node { id: 2, name: "Group".into(), children: vec![] },
+/// ];
+///
+/// let mut clipboard = NodeClipboard::new();
+/// clipboard.copy(&tree, &[1]);
+///
+/// let mut next_id = 10;
+/// let pasted = clipboard.paste(&mut tree, Some(&2), DropPosition::Inside, &mut |_| {
+///     next_id += 1;
+///     next_id
+/// });
+///
+/// assert_eq!(pasted, vec![11]);
+/// assert_eq!(tree[1].children[0].name, "Cube");
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NodeClipboard<N> {
+    /// Deep copies of the clipboard's top-level nodes, in paste order.
+    nodes: Vec<N>,
+
+    /// Whether the nodes were copied or cut.
+    mode: ClipboardMode,
+}
+
+impl<N> Default for NodeClipboard<N> {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            mode: ClipboardMode::Copy,
+        }
+    }
+}
+
+impl<N> NodeClipboard<N>
+where
+    N: TreeOperations,
+{
+    /// Creates an empty clipboard.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads the shared clipboard for this node type from egui's memory.
+    ///
+    /// Every outliner in the same [`egui::Context`] that displays `N` shares
+    /// this clipboard. Returns an empty clipboard if nothing was stored yet.
+    pub fn load(ctx: &egui::Context) -> Self
+    where
+        N: Send + Sync + 'static,
+    {
+        ctx.data(|d| d.get_temp(Self::memory_id()).unwrap_or_default())
+    }
+
+    /// Stores this clipboard as the shared clipboard for this node type.
+    pub fn store(&self, ctx: &egui::Context)
+    where
+        N: Send + Sync + 'static,
+    {
+        ctx.data_mut(|d| d.insert_temp(Self::memory_id(), self.clone()));
+    }
+
+    /// Returns the nodes currently held by the clipboard.
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    /// Returns whether the nodes were copied or cut.
+    pub fn mode(&self) -> ClipboardMode {
+        self.mode
+    }
+
+    /// Returns `true` if the clipboard holds no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Empties the clipboard.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.mode = ClipboardMode::Copy;
+    }
+
+    /// Replaces the clipboard contents with copies of the given nodes.
+    ///
+    /// IDs that are not found in `roots` are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `roots` - The root nodes of the tree to copy from
+    /// * `ids` - The IDs of the nodes to copy, in paste order
+    pub fn copy(&mut self, roots: &[N], ids: &[N::Id]) {
+        self.nodes = ids
+            .iter()
+            .filter_map(|id| find_in_roots(roots, id).cloned())
+            .collect();
+        self.mode = ClipboardMode::Copy;
+    }
+
+    /// Removes the given nodes from the tree and places them on the clipboard.
+    ///
+    /// IDs that are not found in `roots` are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `roots` - The root nodes of the tree to cut from
+    /// * `ids` - The IDs of the nodes to cut, in paste order
+    pub fn cut(&mut self, roots: &mut Vec<N>, ids: &[N::Id]) {
+        self.nodes = ids
+            .iter()
+            .filter_map(|id| remove_from_roots(roots, id))
+            .collect();
+        self.mode = ClipboardMode::Cut;
+    }
+
+    /// Inserts the clipboard contents into the tree.
+    ///
+    /// Copied nodes are inserted with fresh IDs from `next_id`, which is called
+    /// with the original node each new ID is for. Cut nodes keep their IDs on the
+    /// first paste that places any of them; the clipboard then switches to
+    /// [`ClipboardMode::Copy`] so that pasting again does not create duplicate IDs.
+    ///
+    /// A `target` that is no longer in the tree (e.g. a node that was just cut)
+    /// pastes at the root level instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `roots` - The root nodes of the tree to paste into
+    /// * `target` - The node to paste relative to, or `None` to append at the root level
+    /// * `position` - Where to paste relative to `target`
+    /// * `next_id` - Generator producing a fresh, unused ID for each copied node
+    ///
+    /// # Returns
+    ///
+    /// The IDs of the pasted top-level nodes. Nodes that could not be placed
+    /// (unknown target, [`DropPosition::Inside`] a non-collection, or a node type
+    /// without [`TreeOperations::set_id`]) are skipped.
+    pub fn paste<F>(
+        &mut self,
+        roots: &mut Vec<N>,
+        target: Option<&N::Id>,
+        position: DropPosition,
+        next_id: &mut F,
+    ) -> Vec<N::Id>
+    where
+        F: FnMut(&N) -> N::Id,
+    {
+        let mut pasted = Vec::new();
+        let mut taken = HashSet::new();
+        let target = target.filter(|id| find_in_roots(roots, id).is_some());
+
+        for node in &self.nodes {
+            let mut node = node.clone();
+            if self.mode == ClipboardMode::Copy
+                && !reassign_copy(&mut node, next_id, &DuplicateOptions::default(), &mut taken)
+            {
+                continue;
+            }
+
+            let id = node.id();
+            // Keep the clipboard order by placing each node after the previous one
+            let inserted = match (pasted.last(), target) {
+                (Some(previous), _) if position != DropPosition::Inside => {
+                    insert_into_roots(roots, previous, node, DropPosition::After)
+                }
+                (_, Some(target)) => insert_into_roots(roots, target, node, position),
+                (_, None) => {
+                    roots.push(node);
+                    true
+                }
+            };
+
+            if inserted {
+                pasted.push(id);
+            }
+        }
+
+        if !pasted.is_empty() {
+            self.mode = ClipboardMode::Copy;
+        }
+        pasted
+    }
+
+    /// Applies an outliner clipboard command to the tree using the shared clipboard.
+    ///
+    /// This loads the clipboard from egui's memory, performs the cut, copy or
+    /// paste, and stores the clipboard again.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The egui context holding the shared clipboard
+    /// * `roots` - The root nodes of the tree the command was issued for
+    /// * `command` - The command from [`crate::OutlinerResponse::clipboard_command`]
+    /// * `next_id` - Generator producing a fresh, unused ID for each pasted copy
+    ///
+    /// # Returns
+    ///
+    /// The IDs of the pasted top-level nodes (empty for cut and copy).
+    pub fn apply<F>(
+        ctx: &egui::Context,
+        roots: &mut Vec<N>,
+        command: &ClipboardCommand<N::Id>,
+        next_id: &mut F,
+    ) -> Vec<N::Id>
+    where
+        N: Send + Sync + 'static,
+        F: FnMut(&N) -> N::Id,
+    {
+        let mut clipboard = Self::load(ctx);
+        let pasted = match command {
+            ClipboardCommand::Copy(ids) => {
+                clipboard.copy(roots, ids);
+                Vec::new()
+            }
+            ClipboardCommand::Cut(ids) => {
+                clipboard.cut(roots, ids);
+                Vec::new()
+            }
+            ClipboardCommand::Paste {
+                target, position, ..
+            } => clipboard.paste(roots, target.as_ref(), *position, next_id),
+        };
+        clipboard.store(ctx);
+        pasted
+    }
+
+    /// The egui memory ID under which the shared clipboard is stored.
+    ///
+    /// egui keys memory by ID and type, so each node type gets its own clipboard.
+    fn memory_id() -> egui::Id {
+        egui::Id::new("egui_arbor_node_clipboard")
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<N> NodeClipboard<N>
+where
+    N: TreeOperations + serde::Serialize + serde::de::DeserializeOwned,
+{
+    /// Serializes the clipboard to JSON text.
+    ///
+    /// Use this with [`egui::Context::copy_text`] to place nodes on the system
+    /// clipboard, or see [`copy_to_system`](Self::copy_to_system).
+    pub fn to_text(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Deserializes a clipboard from JSON text produced by [`to_text`](Self::to_text).
+    ///
+    /// Pasted text from the system clipboard is available in
+    /// [`ClipboardCommand::Paste`]; text that isn't a serialized clipboard
+    /// returns an error.
+    pub fn from_text(text: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(text)
+    }
+
+    /// Places the serialized clipboard on the system clipboard.
+    pub fn copy_to_system(&self, ctx: &egui::Context) -> Result<(), serde_json::Error> {
+        ctx.copy_text(self.to_text()?);
+        Ok(())
+    }
+
+    /// Applies an outliner clipboard command like [`apply`](Self::apply), also
+    /// exchanging nodes through the system clipboard.
+    ///
+    /// Cut and copy from an outliner built with
+    /// [`Outliner::with_system_clipboard`](crate::Outliner::with_system_clipboard)
+    /// place the serialized clipboard on the system clipboard. A paste whose
+    /// text is a serialized clipboard other than the shared one, such as nodes
+    /// copied in another process, pastes those nodes as copies with fresh IDs.
+    ///
+    /// # Returns
+    ///
+    /// The IDs of the pasted top-level nodes (empty for cut and copy), or the
+    /// error from serializing the clipboard for the system clipboard.
+    pub fn apply_with_system_clipboard<F>(
+        ctx: &egui::Context,
+        roots: &mut Vec<N>,
+        command: &ClipboardCommand<N::Id>,
+        next_id: &mut F,
+    ) -> Result<Vec<N::Id>, serde_json::Error>
+    where
+        N: Send + Sync + 'static,
+        F: FnMut(&N) -> N::Id,
+    {
+        if let ClipboardCommand::Paste {
+            target,
+            position,
+            text: Some(text),
+        } = command
+            && let Ok(mut pasted) = Self::from_text(text)
+            // Our own export pastes from the shared clipboard, keeping cut IDs
+            && *text != Self::load(ctx).to_text()?
+        {
+            pasted.mode = ClipboardMode::Copy;
+            return Ok(pasted.paste(roots, target.as_ref(), *position, next_id));
+        }
+
+        let pasted = Self::apply(ctx, roots, command, next_id);
+        let export = ctx.data_mut(|d| d.remove_temp::<bool>(system_clipboard_request_id()));
+        if matches!(command, ClipboardCommand::Copy(_) | ClipboardCommand::Cut(_))
+            && export.unwrap_or(false)
+        {
+            Self::load(ctx).copy_to_system(ctx)?;
+        }
+        Ok(pasted)
+    }
+}
+
+/// The egui memory ID of the flag an outliner sets with each cut or copy,
+/// telling [`NodeClipboard::apply_with_system_clipboard`] whether to export it.
+#[cfg(feature = "serde")]
+pub(crate) fn system_clipboard_request_id() -> egui::Id {
+    egui::Id::new("egui_arbor_system_clipboard_request")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::OutlinerNode;
+
+    #[derive(Clone, Debug, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    struct TestNode {
+        id: u64,
+        name: String,
+        is_collection: bool,
+        children: Vec<TestNode>,
+    }
+
+    impl TestNode {
+        fn new(id: u64, name: &str, is_collection: bool) -> Self {
+            Self {
+                id,
+                name: name.to_string(),
+                is_collection,
+                children: Vec::new(),
+            }
+        }
+
+        fn with_children(mut self, children: Vec<TestNode>) -> Self {
+            self.children = children;
+            self
+        }
+    }
+
+    impl OutlinerNode for TestNode {
+        type Id = u64;
+
+        fn id(&self) -> Self::Id {
+            self.id
+        }
+
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        fn is_collection(&self) -> bool {
+            self.is_collection
+        }
+
+        fn children(&self) -> &[Self] {
+            &self.children
+        }
+
+        fn children_mut(&mut self) -> &mut Vec<Self> {
+            &mut self.children
+        }
+    }
+
+    impl TreeOperations for TestNode {
+        fn set_id(&mut self, id: u64) -> bool {
+            self.id = id;
+            true
+        }
+    }
+
+    fn sample_tree() -> Vec<TestNode> {
+        vec![
+            TestNode::new(1, "Group", true).with_children(vec![
+                TestNode::new(2, "Cube", false),
+                TestNode::new(3, "Light", false),
+            ]),
+            TestNode::new(4, "Camera", false),
+        ]
+    }
+
+    fn ids(nodes: &[TestNode]) -> Vec<u64> {
+        nodes.iter().map(|n| n.id).collect()
+    }
+
+    #[test]
+    fn test_copy_and_paste_assigns_fresh_ids() {
+        let mut tree = sample_tree();
+        let mut clipboard = NodeClipboard::new();
+        clipboard.copy(&tree, &[2, 3]);
+        assert_eq!(clipboard.mode(), ClipboardMode::Copy);
+        assert_eq!(clipboard.nodes().len(), 2);
+
+        let mut next = 100;
+        let mut next_id = |_: &TestNode| {
+            next += 1;
+            next
+        };
+        let pasted = clipboard.paste(&mut tree, Some(&4), DropPosition::After, &mut next_id);
+
+        assert_eq!(pasted, vec![101, 102]);
+        assert_eq!(ids(&tree), vec![1, 4, 101, 102]);
+        // Originals remain in place
+        assert_eq!(ids(&tree[0].children), vec![2, 3]);
+
+        // Pasting again creates another set of copies
+        let pasted = clipboard.paste(&mut tree, None, DropPosition::After, &mut next_id);
+        assert_eq!(pasted, vec![103, 104]);
+        assert_eq!(tree.len(), 6);
+    }
+
+    #[test]
+    fn test_cut_and_paste_keeps_ids_once() {
+        let mut tree = sample_tree();
+        let mut clipboard = NodeClipboard::new();
+        clipboard.cut(&mut tree, &[2, 4]);
+        assert_eq!(clipboard.mode(), ClipboardMode::Cut);
+        assert_eq!(ids(&tree), vec![1]);
+        assert_eq!(ids(&tree[0].children), vec![3]);
+
+        let pasted = clipboard.paste(&mut tree, Some(&1), DropPosition::Inside, &mut |_| 50);
+        assert_eq!(pasted, vec![2, 4]);
+        assert_eq!(ids(&tree[0].children), vec![3, 2, 4]);
+        assert_eq!(clipboard.mode(), ClipboardMode::Copy);
+    }
+
+    #[test]
+    fn test_paste_before_preserves_order() {
+        let mut tree = sample_tree();
+        let mut clipboard = NodeClipboard::new();
+        clipboard.copy(&tree, &[2, 3]);
+
+        let mut next = 10;
+        clipboard.paste(&mut tree, Some(&1), DropPosition::Before, &mut |_| {
+            next += 1;
+            next
+        });
+        assert_eq!(ids(&tree), vec![11, 12, 1, 4]);
+    }
+
+    #[test]
+    fn test_paste_inside_non_collection_is_skipped() {
+        let mut tree = sample_tree();
+        let mut clipboard = NodeClipboard::new();
+        clipboard.copy(&tree, &[2]);
+
+        let pasted = clipboard.paste(&mut tree, Some(&4), DropPosition::Inside, &mut |_| 20);
+        assert!(pasted.is_empty());
+        assert_eq!(ids(&tree), vec![1, 4]);
+
+        // A failed paste of cut nodes keeps them cut
+        clipboard.cut(&mut tree, &[1]);
+        let pasted = clipboard.paste(&mut tree, Some(&4), DropPosition::Inside, &mut |_| 20);
+        assert!(pasted.is_empty());
+        assert_eq!(clipboard.mode(), ClipboardMode::Cut);
+    }
+
+    #[test]
+    fn test_paste_onto_cut_target_appends_at_root() {
+        let mut tree = sample_tree();
+        let mut clipboard = NodeClipboard::new();
+        clipboard.cut(&mut tree, &[2]);
+
+        // The cut node is still the last selected one, but no longer exists
+        let pasted = clipboard.paste(&mut tree, Some(&2), DropPosition::After, &mut |_| 30);
+        assert_eq!(pasted, vec![2]);
+        assert_eq!(ids(&tree), vec![1, 4, 2]);
+        assert_eq!(clipboard.mode(), ClipboardMode::Copy);
+    }
+
+    #[test]
+    fn test_apply_shares_clipboard_through_context() {
+        let ctx = egui::Context::default();
+        let mut first = sample_tree();
+        let mut second = vec![TestNode::new(10, "Other", true)];
+
+        NodeClipboard::apply(&ctx, &mut first, &ClipboardCommand::Copy(vec![2]), &mut |_| 0);
+        assert_eq!(NodeClipboard::<TestNode>::load(&ctx).nodes().len(), 1);
+
+        let paste = ClipboardCommand::Paste {
+            target: Some(10),
+            position: DropPosition::Inside,
+            text: None,
+        };
+        let pasted = NodeClipboard::apply(&ctx, &mut second, &paste, &mut |_| 11);
+        assert_eq!(pasted, vec![11]);
+        assert_eq!(second[0].children[0].name, "Cube");
+    }
+
+    #[test]
+    fn test_clear() {
+        let tree = sample_tree();
+        let mut clipboard = NodeClipboard::new();
+        clipboard.copy(&tree, &[1, 999]);
+        assert_eq!(clipboard.nodes().len(), 1);
+
+        clipboard.clear();
+        assert!(clipboard.is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_text_round_trip() {
+        let tree = sample_tree();
+        let mut clipboard = NodeClipboard::new();
+        clipboard.copy(&tree, &[1]);
+
+        let text = clipboard.to_text().unwrap();
+        let restored = NodeClipboard::<TestNode>::from_text(&text).unwrap();
+        assert_eq!(restored.nodes(), clipboard.nodes());
+        assert!(NodeClipboard::<TestNode>::from_text("not a clipboard").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_apply_with_system_clipboard_exports_and_pastes_text() {
+        let ctx = egui::Context::default();
+        let mut tree = sample_tree();
+
+        // The outliner requests the export when built with the system clipboard
+        ctx.data_mut(|d| d.insert_temp(system_clipboard_request_id(), true));
+        let output = ctx.run(egui::RawInput::default(), |ctx| {
+            let copy = ClipboardCommand::Copy(vec![2]);
+            NodeClipboard::apply_with_system_clipboard(ctx, &mut tree, &copy, &mut |_| 0).unwrap();
+        });
+        let exported: Vec<_> = output
+            .platform_output
+            .commands
+            .iter()
+            .filter_map(|command| match command {
+                egui::OutputCommand::CopyText(text) => Some(text.clone()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(exported, vec![NodeClipboard::<TestNode>::load(&ctx).to_text().unwrap()]);
+
+        // Nodes copied elsewhere paste as fresh copies
+        let mut foreign = NodeClipboard::new();
+        foreign.cut(&mut sample_tree(), &[4]);
+        let paste = ClipboardCommand::Paste {
+            target: None,
+            position: DropPosition::After,
+            text: Some(foreign.to_text().unwrap()),
+        };
+        let pasted = NodeClipboard::apply_with_system_clipboard(&ctx, &mut tree, &paste, &mut |_| 30).unwrap();
+        assert_eq!(pasted, vec![30]);
+        assert_eq!(tree.last().unwrap().name, "Camera");
+
+        // Our own export pastes from the shared clipboard
+        let paste = ClipboardCommand::Paste {
+            target: None,
+            position: DropPosition::After,
+            text: exported.into_iter().next(),
+        };
+        let pasted = NodeClipboard::apply_with_system_clipboard(&ctx, &mut tree, &paste, &mut |_| 31).unwrap();
+        assert_eq!(pasted, vec![31]);
+        assert_eq!(tree.last().unwrap().name, "Cube");
+    }
+}
@@ -0,0 +1,148 @@
+//! Row decorations (badges) painted after node labels.
+//!
+//! This module provides [`Decoration`], the typed badges returned by
+//! [`OutlinerNode::decorations`](crate::OutlinerNode::decorations), and
+//! [`DecorationTone`], which picks their color from
+//! [`Style::decoration_colors`](crate::Style::decoration_colors).
+
+/// A badge painted right after a node's label.
+///
+/// Decorations are painted in order, left to right. They are purely visual:
+/// clicking one behaves like clicking the label.
+///
+/// # Examples
+///
+/// ```
+/// use egui_arbor::{Decoration, DecorationTone};
+///
+/// let decorations = vec![
+///     Decoration::dot(DecorationTone::Accent),
+///     Decoration::text("LOD2", DecorationTone::Neutral),
+///     Decoration::icon("⚠", DecorationTone::Warning).with_tooltip("Missing texture"),
+/// ];
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Decoration {
+    /// A short text in a rounded pill.
+    Text {
+        /// The text to show.
+        text: String,
+        /// Fill color of the pill.
+        tone: DecorationTone,
+    },
+
+    /// A number in a neutral pill, e.g. a child count.
+    Count(usize),
+
+    /// A small colored dot, e.g. an "unsaved changes" marker.
+    Dot(DecorationTone),
+
+    /// A glyph drawn in the tone's color, with an optional hover tooltip.
+    Icon {
+        /// The glyph to show (typically a single emoji or symbol).
+        icon: String,
+        /// Color of the glyph.
+        tone: DecorationTone,
+        /// Text shown when hovering the icon.
+        tooltip: Option<String>,
+    },
+}
+
+impl Decoration {
+    /// Creates a [`Decoration::Text`] pill.
+    pub fn text(text: impl Into<String>, tone: DecorationTone) -> Self {
+        Decoration::Text {
+            text: text.into(),
+            tone,
+        }
+    }
+
+    /// Creates a [`Decoration::Count`] pill.
+    pub fn count(count: usize) -> Self {
+        Decoration::Count(count)
+    }
+
+    /// Creates a [`Decoration::Dot`].
+    pub fn dot(tone: DecorationTone) -> Self {
+        Decoration::Dot(tone)
+    }
+
+    /// Creates a [`Decoration::Icon`] without a tooltip.
+    pub fn icon(icon: impl Into<String>, tone: DecorationTone) -> Self {
+        Decoration::Icon {
+            icon: icon.into(),
+            tone,
+            tooltip: None,
+        }
+    }
+
+    /// Sets the hover tooltip of an icon decoration.
+    ///
+    /// Other decorations have no tooltip and are returned unchanged.
+    pub fn with_tooltip(mut self, text: impl Into<String>) -> Self {
+        if let Decoration::Icon { tooltip, .. } = &mut self {
+            *tooltip = Some(text.into());
+        }
+        self
+    }
+
+    /// Returns the hover tooltip of this decoration, if any.
+    pub fn tooltip(&self) -> Option<&str> {
+        match self {
+            Decoration::Icon { tooltip, .. } => tooltip.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// The color role of a decoration.
+///
+/// The actual colors come from
+/// [`Style::decoration_colors`](crate::Style::decoration_colors).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DecorationTone {
+    /// Unobtrusive gray, used for counts.
+    #[default]
+    Neutral,
+
+    /// Highlight color, e.g. for "modified" markers.
+    Accent,
+
+    /// Positive state.
+    Success,
+
+    /// Something needs attention.
+    Warning,
+
+    /// Something is broken.
+    Error,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constructors() {
+        assert_eq!(
+            Decoration::text("LOD", DecorationTone::Accent),
+            Decoration::Text {
+                text: "LOD".to_string(),
+                tone: DecorationTone::Accent
+            }
+        );
+        assert_eq!(Decoration::count(3), Decoration::Count(3));
+        assert_eq!(Decoration::dot(DecorationTone::Error), Decoration::Dot(DecorationTone::Error));
+    }
+
+    #[test]
+    fn test_icon_tooltip() {
+        let icon = Decoration::icon("⚠", DecorationTone::Warning);
+        assert_eq!(icon.tooltip(), None);
+        assert_eq!(icon.with_tooltip("Missing texture").tooltip(), Some("Missing texture"));
+
+        // Only icons carry tooltips
+        assert_eq!(Decoration::count(1).with_tooltip("ignored").tooltip(), None);
+    }
+}
@@ -499,6 +499,19 @@ where
             Some(id.clone()),
         );
     }
+
+    fn on_delete(&mut self, ids: &[Id]) {
+        for id in ids {
+            self.selected.remove(id);
+            self.visible.remove(id);
+            self.locked.remove(id);
+            self.log_event(
+                format!("Deleted node {:?}", id),
+                EventType::Delete,
+                Some(id.clone()),
+            );
+        }
+    }
 }
 
 #[cfg(test)]
@@ -699,6 +712,20 @@ mod tests {
         assert!(matches!(entries[0].event_type, EventType::Custom(_)));
     }
 
+    #[test]
+    fn test_on_delete() {
+        let mut actions = DefaultActions::<u64>::with_logging(10);
+        OutlinerActions::<TestNode>::on_select(&mut actions, &1, true);
+        OutlinerActions::<TestNode>::on_lock_toggle(&mut actions, &1);
+
+        OutlinerActions::<TestNode>::on_delete(&mut actions, &[1, 2]);
+
+        assert_eq!(actions.selected_count(), 0);
+        assert_eq!(actions.locked_count(), 0);
+        let log = actions.event_log().unwrap();
+        assert_eq!(log.filter_by_type(&EventType::Delete).count(), 2);
+    }
+
     #[test]
     fn test_default() {
         let actions = DefaultActions::<u64>::default();
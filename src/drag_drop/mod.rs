@@ -20,10 +20,23 @@ where
     pub dragging: Option<Id>,
 
     /// The ID of the node currently being hovered over as a potential drop target.
+    ///
+    /// `None` while hovering the empty space below the tree, where the drop
+    /// position is [`DropPosition::End`].
     pub hover_target: Option<Id>,
 
     /// The position where the dragged node would be dropped relative to the hover target.
     pub drop_position: Option<DropPosition>,
+
+    /// The row the drop line is drawn on and the depth it is indented to, for
+    /// indent-aware drops (see [`DragDropVisuals::indent_aware`]).
+    ///
+    /// The row differs from the hover target when the drop outdents to an ancestor.
+    pub drop_line: Option<(Id, usize)>,
+
+    /// The hovered row (`None` for [`DropPosition::End`]), position and reason
+    /// of a drop rejected by [`OutlinerActions::can_drop`](crate::OutlinerActions::can_drop).
+    pub rejected: Option<(Option<Id>, DropPosition, String)>,
 }
 
 impl<Id> Default for DragDropState<Id>
@@ -35,6 +48,8 @@ where
             dragging: None,
             hover_target: None,
             drop_position: None,
+            drop_line: None,
+            rejected: None,
         }
     }
 }
@@ -68,12 +83,75 @@ where
     pub fn update_hover(&mut self, target: Id, position: DropPosition) {
         self.hover_target = Some(target);
         self.drop_position = Some(position);
+        self.drop_line = None;
+        self.rejected = None;
+    }
+
+    /// Sets the empty space below the tree as the drop location, appending the
+    /// dragged nodes at the root level ([`DropPosition::End`]).
+    ///
+    /// Such drops have no target node.
+    pub fn hover_end(&mut self) {
+        self.hover_target = None;
+        self.drop_position = Some(DropPosition::End);
+        self.drop_line = None;
+        self.rejected = None;
+    }
+
+    /// Records a hovered drop that was rejected, clearing the hover target so
+    /// releasing here doesn't drop.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The ID of the hovered row
+    /// * `position` - The rejected drop position
+    /// * `reason` - Why the drop is not allowed
+    pub fn reject_hover(&mut self, row: Id, position: DropPosition, reason: String) {
+        self.clear_hover();
+        self.rejected = Some((Some(row), position, reason));
+    }
+
+    /// Records that a drop into the empty space below the tree was rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `reason` - Why the drop is not allowed
+    pub fn reject_end(&mut self, reason: String) {
+        self.clear_hover();
+        self.rejected = Some((None, DropPosition::End, reason));
+    }
+
+    /// Returns the hovered row (`None` for [`DropPosition::End`]), position and
+    /// reason of a rejected drop, if any.
+    pub fn rejection(&self) -> Option<(Option<&Id>, DropPosition, &str)> {
+        self.rejected
+            .as_ref()
+            .map(|(row, position, reason)| (row.as_ref(), *position, reason.as_str()))
+    }
+
+    /// Sets the row and depth of an indented drop line for the current hover.
+    ///
+    /// Call this after [`update_hover`](Self::update_hover), which clears it.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The ID of the row the line is drawn on
+    /// * `depth` - The depth the dropped nodes would end up at
+    pub fn set_drop_line(&mut self, row: Id, depth: usize) {
+        self.drop_line = Some((row, depth));
+    }
+
+    /// Returns the row and depth of the indented drop line, if any.
+    pub fn drop_line(&self) -> Option<(&Id, usize)> {
+        self.drop_line.as_ref().map(|(row, depth)| (row, *depth))
     }
 
     /// Clears the hover state.
     pub fn clear_hover(&mut self) {
         self.hover_target = None;
         self.drop_position = None;
+        self.drop_line = None;
+        self.rejected = None;
     }
 
     /// Ends the drag operation and returns the drop information if valid.
@@ -81,7 +159,10 @@ where
     /// # Returns
     ///
     /// A tuple of `(source_id, target_id, position)` if a valid drop occurred,
-    /// or `None` if the drag was cancelled or invalid.
+    /// or `None` if the drag was cancelled or invalid. Drops at
+    /// [`DropPosition::End`] have no target and return `None` too, so check
+    /// [`current_drop_position`](Self::current_drop_position) before ending the
+    /// drag to handle them.
     pub fn end_drag(&mut self) -> Option<(Id, Id, DropPosition)> {
         let result = if let (Some(source), Some(target), Some(position)) =
             (&self.dragging, &self.hover_target, &self.drop_position)
@@ -94,6 +175,8 @@ where
         self.dragging = None;
         self.hover_target = None;
         self.drop_position = None;
+        self.drop_line = None;
+        self.rejected = None;
 
         result
     }
@@ -103,6 +186,8 @@ where
         self.dragging = None;
         self.hover_target = None;
         self.drop_position = None;
+        self.drop_line = None;
+        self.rejected = None;
     }
 
     /// Returns whether a drag operation is currently active.
@@ -183,6 +268,9 @@ where
 /// - Middle 50%: Inside (if the node is a collection)
 /// - Bottom 25%: After
 ///
+/// These are the default zones; see [`DragDropVisuals::drop_position`] for
+/// configurable ones.
+///
 /// # Arguments
 ///
 /// * `cursor_y` - The Y coordinate of the cursor
@@ -197,22 +285,111 @@ pub fn calculate_drop_position(
     rect: egui::Rect,
     is_collection: bool,
 ) -> DropPosition {
-    let relative_y = (cursor_y - rect.top()) / rect.height();
-
-    if relative_y < 0.25 {
-        DropPosition::Before
-    } else if relative_y > 0.75 {
-        DropPosition::After
-    } else if is_collection {
-        DropPosition::Inside
-    } else {
-        // For non-collections in the middle zone, prefer After
-        DropPosition::After
+    DragDropVisuals::default().drop_position(cursor_y, rect, is_collection)
+}
+
+/// Which part of a row starts a drag.
+///
+/// Set with [`Outliner::with_drag_mode`](crate::Outliner::with_drag_mode).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DragMode {
+    /// Dragging the label starts a drag.
+    #[default]
+    Label,
+
+    /// Only a grip handle after the label starts a drag; the label just
+    /// selects.
+    Handle,
+
+    /// Nodes can't be dragged, e.g. in read-only views.
+    Disabled,
+}
+
+/// What a drop does with the dragged nodes.
+///
+/// Decided by the modifiers held when the nodes are dropped (see
+/// [`from_modifiers`](Self::from_modifiers)) and reported in
+/// [`DropEvent::operation`](crate::DropEvent::operation).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DropOperation {
+    /// Move the nodes to the drop location.
+    #[default]
+    Move,
+
+    /// Place copies of the nodes at the drop location, keeping the originals.
+    Copy,
+
+    /// Place links (instances) of the nodes at the drop location.
+    Link,
+}
+
+impl DropOperation {
+    /// Returns the operation for the held modifiers.
+    ///
+    /// Alt links, Ctrl (Cmd on macOS) copies, and anything else moves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_arbor::DropOperation;
+    ///
+    /// assert_eq!(DropOperation::from_modifiers(egui::Modifiers::COMMAND), DropOperation::Copy);
+    /// assert_eq!(DropOperation::from_modifiers(egui::Modifiers::ALT), DropOperation::Link);
+    /// assert_eq!(DropOperation::from_modifiers(egui::Modifiers::NONE), DropOperation::Move);
+    /// ```
+    pub fn from_modifiers(modifiers: egui::Modifiers) -> Self {
+        if modifiers.alt {
+            DropOperation::Link
+        } else if modifiers.command {
+            DropOperation::Copy
+        } else {
+            DropOperation::Move
+        }
     }
+
+    /// Returns the mouse cursor shown while dragging with this operation.
+    pub fn cursor_icon(&self) -> egui::CursorIcon {
+        match self {
+            DropOperation::Move => egui::CursorIcon::Grabbing,
+            DropOperation::Copy => egui::CursorIcon::Copy,
+            DropOperation::Link => egui::CursorIcon::Alias,
+        }
+    }
+}
+
+/// The nodes an outliner is dragging, published as egui's drag-and-drop payload.
+///
+/// While a drag is in progress the outliner keeps this payload up to date with
+/// [`egui::DragAndDrop`], so other panels can show a drop preview and accept
+/// the nodes, e.g. with [`egui::Response::dnd_release_payload`]. Releasing the
+/// drag outside the outliner is also reported as
+/// [`OutlinerEvent::DroppedOutside`](crate::OutlinerEvent::DroppedOutside).
+///
+/// # Examples
+///
+/// ```ignore
+/// let response = ui.allocate_rect(viewport_rect, egui::Sense::hover());
+/// if let Some(payload) = response.dnd_release_payload::<DragPayload<u64>>() {
+///     spawn_instances(&payload.nodes, payload.pointer);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DragPayload<Id> {
+    /// The ID of the outliner the drag started in.
+    pub outliner: egui::Id,
+
+    /// Every node being dragged (the selection, if the dragged node was selected).
+    pub nodes: Vec<Id>,
+
+    /// The pointer position when the outliner last updated the payload.
+    pub pointer: egui::Pos2,
 }
 
 /// Visual feedback configuration for drag-drop operations.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
 pub struct DragDropVisuals {
     /// Color for the drop indicator line (Before/After positions).
     pub drop_line_color: egui::Color32,
@@ -228,6 +405,46 @@ pub struct DragDropVisuals {
 
     /// Opacity multiplier for invalid drop targets.
     pub invalid_target_opacity: f32,
+
+    /// Corner radius of the drop target and drag source highlights.
+    pub corner_radius: f32,
+
+    /// Opacity multiplier of [`drop_target_color`](Self::drop_target_color)
+    /// when it fills the empty area of an End drop.
+    pub end_drop_opacity: f32,
+
+    /// Whether a preview of the dragged nodes follows the pointer.
+    pub show_preview: bool,
+
+    /// Offset of the preview's top-left corner from the pointer.
+    pub preview_offset: egui::Vec2,
+
+    /// Opacity of the preview, from 0.0 (invisible) to 1.0.
+    pub preview_opacity: f32,
+
+    /// Background of the preview. If `None`, uses egui's popup background.
+    pub preview_fill: Option<egui::Color32>,
+
+    /// Text color of the preview. If `None`, uses egui's text color.
+    pub preview_text_color: Option<egui::Color32>,
+
+    /// Fraction of a row's height, from its top, that drops Before the row.
+    pub before_zone: f32,
+
+    /// Fraction of a row's height, from its bottom, that drops After the row.
+    pub after_zone: f32,
+
+    /// Whether the middle zone of a non-collection row is split at the row's
+    /// center between Before and After. If `false`, it all drops After.
+    pub split_leaf_middle: bool,
+
+    /// Whether the horizontal pointer position picks the depth of a drop after
+    /// the last child of a collection.
+    ///
+    /// Moving the pointer left of the row's indentation outdents the drop to
+    /// the parent's level (or further, if the parent is a last child too). The
+    /// drop line is indented to show the resulting depth.
+    pub indent_aware: bool,
 }
 
 impl Default for DragDropVisuals {
@@ -238,11 +455,94 @@ impl Default for DragDropVisuals {
             drop_target_color: egui::Color32::from_rgba_unmultiplied(100, 150, 255, 50),
             drag_source_color: egui::Color32::from_rgba_unmultiplied(100, 150, 255, 100),
             invalid_target_opacity: 0.3,
+            corner_radius: 2.0,
+            end_drop_opacity: 0.5,
+            show_preview: true,
+            preview_offset: egui::vec2(12.0, 12.0),
+            preview_opacity: 0.85,
+            preview_fill: None,
+            preview_text_color: None,
+            before_zone: 0.25,
+            after_zone: 0.25,
+            split_leaf_middle: false,
+            indent_aware: false,
         }
     }
 }
 
 impl DragDropVisuals {
+    /// Determines the drop position from the cursor position within a row,
+    /// using the configured [`before_zone`](Self::before_zone) and
+    /// [`after_zone`](Self::after_zone).
+    ///
+    /// The middle zone drops Inside collections. For other rows it drops After,
+    /// or is split at the center if [`split_leaf_middle`](Self::split_leaf_middle) is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor_y` - The Y coordinate of the cursor
+    /// * `rect` - The rectangle of the row being hovered over
+    /// * `is_collection` - Whether the target node can accept children
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_arbor::{DragDropVisuals, DropPosition};
+    ///
+    /// let visuals = DragDropVisuals {
+    ///     before_zone: 0.5,
+    ///     after_zone: 0.5,
+    ///     ..Default::default()
+    /// };
+    /// let rect = egui::Rect::from_min_size(egui::pos2(0.0, 0.0), egui::vec2(100.0, 20.0));
+    /// assert_eq!(visuals.drop_position(4.0, rect, true), DropPosition::Before);
+    /// assert_eq!(visuals.drop_position(16.0, rect, true), DropPosition::After);
+    /// ```
+    pub fn drop_position(&self, cursor_y: f32, rect: egui::Rect, is_collection: bool) -> DropPosition {
+        let relative_y = (cursor_y - rect.top()) / rect.height();
+
+        if relative_y < self.before_zone {
+            DropPosition::Before
+        } else if relative_y > 1.0 - self.after_zone {
+            DropPosition::After
+        } else if is_collection {
+            DropPosition::Inside
+        } else if self.split_leaf_middle && relative_y < 0.5 {
+            DropPosition::Before
+        } else {
+            // For non-collections in the middle zone, prefer After
+            DropPosition::After
+        }
+    }
+
+    /// Returns the text of the default drag preview: the dragged node's name
+    /// (after its icon, if it has one), or "N items" when dragging several nodes.
+    ///
+    /// Copies are prefixed with "+" and links with "🔗".
+    pub fn preview_text<N: OutlinerNode>(nodes: &[&N], operation: DropOperation) -> String {
+        let text = match nodes {
+            [node] if node.icon().is_some() => format!("📄 {}", node.name()),
+            [node] => node.name().to_string(),
+            nodes => format!("{} items", nodes.len()),
+        };
+        match operation {
+            DropOperation::Move => text,
+            DropOperation::Copy => format!("+ {text}"),
+            DropOperation::Link => format!("🔗 {text}"),
+        }
+    }
+
+    /// Returns these visuals with the drop indicator colors faded by
+    /// [`invalid_target_opacity`](Self::invalid_target_opacity), for drawing
+    /// rejected drop targets.
+    pub fn for_invalid_target(&self) -> Self {
+        Self {
+            drop_line_color: self.drop_line_color.gamma_multiply(self.invalid_target_opacity),
+            drop_target_color: self.drop_target_color.gamma_multiply(self.invalid_target_opacity),
+            ..self.clone()
+        }
+    }
+
     /// Draws a drop indicator line at the specified position.
     ///
     /// # Arguments
@@ -260,6 +560,7 @@ impl DragDropVisuals {
             DropPosition::Before => rect.top(),
             DropPosition::After => rect.bottom(),
             DropPosition::Inside => return, // Inside uses highlight instead
+            DropPosition::End => return,    // End uses draw_end_drop instead
         };
 
         let start = egui::pos2(rect.left(), y);
@@ -278,7 +579,24 @@ impl DragDropVisuals {
     /// * `painter` - The egui painter to draw with
     /// * `rect` - The rectangle of the target node
     pub fn draw_drop_highlight(&self, painter: &egui::Painter, rect: egui::Rect) {
-        painter.rect_filled(rect, 2.0, self.drop_target_color);
+        painter.rect_filled(rect, self.corner_radius, self.drop_target_color);
+    }
+
+    /// Draws the drop zone for an End drop (appending at the root level).
+    ///
+    /// Tints the empty area below the tree and draws an unindented drop line
+    /// along its top edge.
+    ///
+    /// # Arguments
+    ///
+    /// * `painter` - The egui painter to draw with
+    /// * `rect` - The empty area below the last row
+    pub fn draw_end_drop(&self, painter: &egui::Painter, rect: egui::Rect) {
+        painter.rect_filled(rect, self.corner_radius, self.drop_target_color.gamma_multiply(self.end_drop_opacity));
+        painter.line_segment(
+            [rect.left_top(), rect.right_top()],
+            egui::Stroke::new(self.drop_line_thickness, self.drop_line_color),
+        );
     }
 
     /// Draws visual feedback for the node being dragged.
@@ -288,7 +606,7 @@ impl DragDropVisuals {
     /// * `painter` - The egui painter to draw with
     /// * `rect` - The rectangle of the dragged node
     pub fn draw_drag_source(&self, painter: &egui::Painter, rect: egui::Rect) {
-        painter.rect_filled(rect, 2.0, self.drag_source_color);
+        painter.rect_filled(rect, self.corner_radius, self.drag_source_color);
     }
 }
 
@@ -351,11 +669,6 @@ mod tests {
                 children: Vec::new(),
             }
         }
-
-        fn with_children(mut self, children: Vec<TestNode>) -> Self {
-            self.children = children;
-            self
-        }
     }
 
     #[test]
@@ -569,6 +882,102 @@ mod tests {
         assert_eq!(position, DropPosition::After);
     }
 
+    #[test]
+    fn test_drop_position_custom_zones() {
+        let rect = egui::Rect::from_min_size(egui::pos2(0.0, 0.0), egui::vec2(100.0, 40.0));
+        let visuals = DragDropVisuals {
+            before_zone: 0.1,
+            after_zone: 0.4,
+            ..Default::default()
+        };
+
+        assert_eq!(visuals.drop_position(3.0, rect, true), DropPosition::Before);
+        assert_eq!(visuals.drop_position(8.0, rect, true), DropPosition::Inside);
+        assert_eq!(visuals.drop_position(25.0, rect, true), DropPosition::After);
+
+        // Leaf rows can split their middle zone instead of always dropping After
+        assert_eq!(visuals.drop_position(8.0, rect, false), DropPosition::After);
+        let split = DragDropVisuals { split_leaf_middle: true, ..visuals };
+        assert_eq!(split.drop_position(8.0, rect, false), DropPosition::Before);
+        assert_eq!(split.drop_position(22.0, rect, false), DropPosition::After);
+    }
+
+    #[test]
+    fn test_reject_hover() {
+        let mut state = DragDropState::new();
+        state.start_drag(1u64);
+        state.update_hover(2, DropPosition::Inside);
+        state.reject_hover(3, DropPosition::Inside, "Lights can't contain meshes".to_string());
+        assert_eq!(state.rejection(), Some((Some(&3), DropPosition::Inside, "Lights can't contain meshes")));
+
+        // Rejected drops don't drop
+        assert_eq!(state.current_drop_position(), None);
+        assert_eq!(state.end_drag(), None);
+        assert_eq!(state.rejection(), None);
+    }
+
+    #[test]
+    fn test_hover_end_has_no_target() {
+        let mut state = DragDropState::new();
+        state.start_drag(1u64);
+        state.update_hover(2, DropPosition::After);
+        state.hover_end();
+        assert_eq!(state.hover_target, None);
+        assert_eq!(state.current_drop_position(), Some(DropPosition::End));
+
+        state.reject_end("Only cameras go at the root".to_string());
+        assert_eq!(state.rejection(), Some((None, DropPosition::End, "Only cameras go at the root")));
+        assert_eq!(state.current_drop_position(), None);
+    }
+
+    #[test]
+    fn test_for_invalid_target() {
+        let visuals = DragDropVisuals::default();
+        let invalid = visuals.for_invalid_target();
+        assert!(invalid.drop_line_color.a() < visuals.drop_line_color.a());
+        assert!(invalid.drop_target_color.a() < visuals.drop_target_color.a());
+        assert_eq!(invalid.drop_line_thickness, visuals.drop_line_thickness);
+    }
+
+    #[test]
+    fn test_drop_line_cleared_by_hover_changes() {
+        let mut state = DragDropState::new();
+        state.start_drag(1u64);
+        state.update_hover(2, DropPosition::After);
+        state.set_drop_line(3, 1);
+        assert_eq!(state.drop_line(), Some((&3, 1)));
+
+        state.update_hover(4, DropPosition::Inside);
+        assert_eq!(state.drop_line(), None);
+
+        state.set_drop_line(4, 0);
+        assert_eq!(state.end_drag(), Some((1, 4, DropPosition::Inside)));
+        assert_eq!(state.drop_line(), None);
+    }
+
+    #[test]
+    fn test_preview_text() {
+        let cube = TestNode::new(1, "Cube", false);
+        let light = TestNode::new(2, "Light", false);
+        assert_eq!(DragDropVisuals::preview_text(&[&cube], DropOperation::Move), "📄 Cube");
+        assert_eq!(DragDropVisuals::preview_text(&[&cube, &light], DropOperation::Move), "2 items");
+        assert_eq!(DragDropVisuals::preview_text(&[&cube], DropOperation::Copy), "+ 📄 Cube");
+        assert_eq!(DragDropVisuals::preview_text(&[&cube, &light], DropOperation::Link), "🔗 2 items");
+    }
+
+    #[test]
+    fn test_drop_operation_from_modifiers() {
+        assert_eq!(DropOperation::from_modifiers(egui::Modifiers::NONE), DropOperation::Move);
+        assert_eq!(DropOperation::from_modifiers(egui::Modifiers::SHIFT), DropOperation::Move);
+        assert_eq!(DropOperation::from_modifiers(egui::Modifiers::COMMAND), DropOperation::Copy);
+        assert_eq!(DropOperation::from_modifiers(egui::Modifiers::ALT), DropOperation::Link);
+        assert_eq!(
+            DropOperation::from_modifiers(egui::Modifiers::ALT | egui::Modifiers::COMMAND),
+            DropOperation::Link
+        );
+        assert_eq!(DropOperation::default(), DropOperation::Move);
+    }
+
     #[test]
     fn test_drag_drop_visuals_default() {
         let visuals = DragDropVisuals::default();
@@ -43,6 +43,9 @@ pub enum EventType {
     /// Node rename event.
     Rename,
     
+    /// Node deletion event.
+    Delete,
+    
     /// Custom event type with a string identifier.
     Custom(String),
 }
@@ -65,6 +68,7 @@ impl EventType {
             EventType::Lock => "Lock",
             EventType::DragDrop => "DragDrop",
             EventType::Rename => "Rename",
+            EventType::Delete => "Delete",
             EventType::Custom(s) => s.as_str(),
         }
     }
@@ -419,6 +423,7 @@ mod tests {
         assert_eq!(EventType::Lock.as_str(), "Lock");
         assert_eq!(EventType::DragDrop.as_str(), "DragDrop");
         assert_eq!(EventType::Rename.as_str(), "Rename");
+        assert_eq!(EventType::Delete.as_str(), "Delete");
         assert_eq!(EventType::Custom("Test".into()).as_str(), "Test");
     }
 
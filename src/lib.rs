@@ -9,13 +9,17 @@
 //! - **Expand/Collapse**: Navigate through tree hierarchy with visual expand/collapse arrows
 //! - **Drag & Drop**: Reorder and reparent nodes with Before/After/Inside positioning
 //! - **Action Icons**: Built-in visibility, lock, and selection toggles with custom icon support
-//! - **Inline Editing**: Double-click to rename nodes with keyboard shortcuts
+//! - **Inline Widgets**: Embed sliders, swatches or progress bars in rows via [`OutlinerActions::row_widget_ui`]
+//! - **Decorations**: Badges, counts, dots and icons after labels via [`OutlinerNode::decorations`]
+//! - **Inline Editing**: Double-click to rename nodes, with validation and name normalization
 //! - **Multi-Selection**: Select multiple nodes with Shift-click, Ctrl/Cmd-click, or box selection
 //! - **Customizable Styling**: Configure indentation, colors, icons, and spacing
 //! - **Trait-Based Integration**: Works with any data structure implementing [`OutlinerNode`]
 //! - **State Persistence**: Automatic state management via egui's memory system
-//! - **Tree Operations**: Built-in helpers for common tree manipulations (rename, remove, insert)
+//! - **Tree Operations**: Built-in helpers for common tree manipulations (rename, remove, insert, duplicate)
 //! - **Default Actions**: Ready-to-use [`OutlinerActions`] implementation with event logging
+//! - **Clipboard**: Cut, copy and paste nodes within and across outliners
+//! - **Display Sorting**: Show siblings by name, collections first, or a custom order
 //!
 //! # Multi-Selection
 //!
@@ -79,34 +83,48 @@
 //! - [`OutlinerNode`] - Trait to implement on your data structures
 //! - [`OutlinerActions`] - Trait for handling user interactions
 //! - [`OutlinerResponse`] - Response type containing event information
+//! - [`OutlinerEvent`] - A single interaction, collected in order in the response
 //! - [`OutlinerState`] - Persistent state for expansion and editing
 //! - [`Style`] - Visual styling configuration
 //! - [`DragDropState`] - State tracking for drag-drop operations
 //!
 //! # Helper Modules
 //!
-//! - [`tree_ops`] - Tree manipulation operations (rename, remove, insert)
+//! - [`tree_ops`] - Tree manipulation operations (rename, remove, insert, duplicate)
+//! - [`tree_diff`] - Structural diffs between tree versions, applicable as patches
+//! - [`clipboard`] - Typed node clipboard for cut/copy/paste
+//! - [`sort`] - Display sort modes and natural name comparison
+//! - [`decoration`] - Typed row badges painted after labels
 //! - [`default_actions`] - Ready-to-use actions implementation with state tracking
 //! - [`event_log`] - Event logging system for tracking user interactions
 //!
 //! # Optional Features
 //!
-//! - `serde` - Enable serialization support for state persistence
+//! - `serde` - Enable serialization support for state persistence, clipboard text and JSON themes
+//! - `ron` - Load and save [`Theme`]s as RON (implies `serde`)
 
+pub mod clipboard;
+pub mod decoration;
 pub mod default_actions;
 pub mod drag_drop;
 pub mod event_log;
 pub mod outliner;
+pub mod rename;
 pub mod response;
+pub mod sort;
 pub mod state;
 pub mod style;
 pub mod traits;
+pub mod tree_diff;
 pub mod tree_ops;
 
 // Re-export main types for convenience
-pub use drag_drop::{DragDropState, DragDropVisuals};
+pub use decoration::{Decoration, DecorationTone};
+pub use drag_drop::{DragDropState, DragDropVisuals, DragMode, DragPayload, DropOperation};
 pub use outliner::Outliner;
-pub use response::{DropEvent, OutlinerResponse};
+pub use rename::RenameOptions;
+pub use response::{ClipboardCommand, DropEvent, OutlinerEvent, OutlinerResponse};
+pub use sort::SortMode;
 pub use state::{BoxSelectionState, OutlinerState};
-pub use style::{ExpandIconStyle, Style};
-pub use traits::{ActionIcon, DropPosition, IconType, OutlinerActions, OutlinerNode};
\ No newline at end of file
+pub use style::{DecorationColors, ExpandIconStyle, IndentGuides, LabelStyle, Style, Theme};
+pub use traits::{ActionIcon, DropPosition, IconType, OutlinerActions, OutlinerNode, RowInfo};
\ No newline at end of file
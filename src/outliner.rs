@@ -5,11 +5,14 @@
 //! custom actions.
 
 use crate::{
-    drag_drop::{calculate_drop_position, validate_drop, DragDropVisuals},
-    response::{DropEvent, OutlinerResponse},
+    decoration::Decoration,
+    drag_drop::{validate_drop, DragDropVisuals, DragMode, DragPayload, DropOperation},
+    rename::RenameOptions,
+    response::{ClipboardCommand, DropEvent, OutlinerEvent, OutlinerResponse},
+    sort::SortMode,
     state::OutlinerState,
-    style::Style,
-    traits::{ActionIcon, DropPosition, OutlinerActions, OutlinerNode},
+    style::{IndentGuides, LabelStyle, Style, Theme},
+    traits::{ActionIcon, DropPosition, OutlinerActions, OutlinerNode, RowInfo},
 };
 
 /// The main outliner widget for rendering hierarchical tree structures.
@@ -21,6 +24,9 @@ use crate::{
 /// - Action icons (visibility, lock, selection, custom)
 /// - Keyboard navigation and shortcuts
 ///
+/// Keyboard shortcuts (such as Ctrl/Cmd+D to duplicate) apply while the pointer
+/// is over the outliner and no text field has keyboard focus.
+///
 /// # Examples
 ///
 /// ```ignore
@@ -42,6 +48,48 @@ pub struct Outliner {
 
     /// Visual configuration for drag-drop operations.
     drag_drop_visuals: DragDropVisuals,
+
+    /// Shortcut that requests duplication of the selected nodes, if enabled.
+    duplicate_shortcut: Option<egui::KeyboardShortcut>,
+
+    /// Whether cut/copy/paste shortcuts are reported.
+    clipboard_enabled: bool,
+
+    /// Whether cut and copy also place node names on the system clipboard.
+    system_clipboard: bool,
+
+    /// Where pasted nodes go relative to the most recently selected node.
+    paste_position: DropPosition,
+
+    /// Whether Delete/Backspace deletes the selected nodes.
+    delete_enabled: bool,
+
+    /// Whether deleting collections with children asks for confirmation first.
+    confirm_delete: bool,
+
+    /// Display order of sibling nodes.
+    sort_mode: SortMode,
+
+    /// Whether action icons show hover tooltips.
+    action_tooltips: bool,
+
+    /// Normalization and commit behavior of inline renaming.
+    rename_options: RenameOptions,
+
+    /// Whether collapsed collections show their descendant count.
+    descendant_counts: bool,
+
+    /// Whether a secondary click cancels an ongoing drag.
+    secondary_click_cancels_drag: bool,
+
+    /// Which part of a row starts a drag.
+    drag_mode: DragMode,
+
+    /// Distance in points the pointer must move before a drag starts.
+    drag_threshold: f32,
+
+    /// Time in seconds the button must be held before a drag starts.
+    drag_delay: f32,
 }
 
 impl Outliner {
@@ -64,6 +112,23 @@ impl Outliner {
             id: id.into(),
             style: Style::default(),
             drag_drop_visuals: DragDropVisuals::default(),
+            duplicate_shortcut: Some(egui::KeyboardShortcut::new(
+                egui::Modifiers::COMMAND,
+                egui::Key::D,
+            )),
+            clipboard_enabled: true,
+            system_clipboard: false,
+            paste_position: DropPosition::After,
+            delete_enabled: true,
+            confirm_delete: false,
+            sort_mode: SortMode::Manual,
+            action_tooltips: true,
+            rename_options: RenameOptions::default(),
+            descendant_counts: false,
+            secondary_click_cancels_drag: true,
+            drag_mode: DragMode::Label,
+            drag_threshold: 0.0,
+            drag_delay: 0.0,
         }
     }
 
@@ -105,6 +170,324 @@ impl Outliner {
         self
     }
 
+    /// Sets both the style and the drag-drop visuals from a theme.
+    ///
+    /// # Arguments
+    ///
+    /// * `theme` - The theme to use, e.g. a preset such as [`Theme::vscode`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_arbor::{Outliner, Theme};
+    ///
+    /// let outliner = Outliner::new("my_outliner").with_theme(Theme::compact());
+    /// ```
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.style = theme.style;
+        self.drag_drop_visuals = theme.drag_drop;
+        self
+    }
+
+    /// Sets the shortcut that requests duplication of the selected nodes.
+    ///
+    /// When pressed, the top-most selected nodes are reported through
+    /// [`OutlinerResponse::duplicate_requested`]. Pass `None` to disable the shortcut.
+    ///
+    /// Default: Ctrl/Cmd+D
+    ///
+    /// # Arguments
+    ///
+    /// * `shortcut` - The shortcut to use, or `None` to disable it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_arbor::Outliner;
+    ///
+    /// let outliner = Outliner::new("my_outliner")
+    ///     .with_duplicate_shortcut(Some(egui::KeyboardShortcut::new(
+    ///         egui::Modifiers::SHIFT,
+    ///         egui::Key::D,
+    ///     )));
+    /// ```
+    pub fn with_duplicate_shortcut(mut self, shortcut: Option<egui::KeyboardShortcut>) -> Self {
+        self.duplicate_shortcut = shortcut;
+        self
+    }
+
+    /// Enables or disables the cut/copy/paste shortcuts.
+    ///
+    /// When enabled, Ctrl/Cmd+X, C and V are reported through
+    /// [`OutlinerResponse::clipboard_command`].
+    ///
+    /// Default: enabled
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_arbor::Outliner;
+    ///
+    /// let outliner = Outliner::new("read_only").with_clipboard(false);
+    /// ```
+    pub fn with_clipboard(mut self, enabled: bool) -> Self {
+        self.clipboard_enabled = enabled;
+        self
+    }
+
+    /// Sets whether cut and copy also go to the system clipboard.
+    ///
+    /// With the `serde` feature, applying the command with
+    /// [`NodeClipboard::apply_with_system_clipboard`](crate::clipboard::NodeClipboard::apply_with_system_clipboard)
+    /// places the serialized clipboard there, so the nodes can be pasted into
+    /// another outliner or process. Without it, the outliner places the names
+    /// of the affected nodes there as plain text, which can't be pasted back
+    /// as nodes.
+    ///
+    /// This replaces whatever the user had on the system clipboard, so it is
+    /// off by default.
+    ///
+    /// Default: disabled
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_arbor::Outliner;
+    ///
+    /// let outliner = Outliner::new("scene").with_system_clipboard(true);
+    /// ```
+    pub fn with_system_clipboard(mut self, enabled: bool) -> Self {
+        self.system_clipboard = enabled;
+        self
+    }
+
+    /// Sets where pasted nodes are placed relative to the most recently selected node.
+    ///
+    /// [`DropPosition::Inside`] falls back to [`DropPosition::After`] when the
+    /// target is not a collection.
+    ///
+    /// Default: [`DropPosition::After`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_arbor::{DropPosition, Outliner};
+    ///
+    /// let outliner = Outliner::new("my_outliner")
+    ///     .with_paste_position(DropPosition::Inside);
+    /// ```
+    pub fn with_paste_position(mut self, position: DropPosition) -> Self {
+        self.paste_position = position;
+        self
+    }
+
+    /// Enables or disables deleting the selection with Delete or Backspace.
+    ///
+    /// Deletions call [`OutlinerActions::on_delete`] and are reported through
+    /// [`OutlinerResponse::delete_requested`].
+    ///
+    /// Default: enabled
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_arbor::Outliner;
+    ///
+    /// let outliner = Outliner::new("read_only").with_delete(false);
+    /// ```
+    pub fn with_delete(mut self, enabled: bool) -> Self {
+        self.delete_enabled = enabled;
+        self
+    }
+
+    /// Asks for confirmation before deleting collections that have children.
+    ///
+    /// When enabled, deleting a selection that contains a non-empty collection
+    /// opens a small confirmation popup; the deletion only happens once accepted.
+    ///
+    /// Default: disabled
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_arbor::Outliner;
+    ///
+    /// let outliner = Outliner::new("my_outliner").with_delete_confirmation(true);
+    /// ```
+    pub fn with_delete_confirmation(mut self, confirm: bool) -> Self {
+        self.confirm_delete = confirm;
+        self
+    }
+
+    /// Sets how sibling nodes are ordered for display.
+    ///
+    /// Sorting never modifies the node slices passed to [`show`](Self::show). While
+    /// a non-manual sort is active, drops next to a node are reported as
+    /// [`DropPosition::After`] since the sort decides the final order.
+    ///
+    /// Default: [`SortMode::Manual`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_arbor::{Outliner, SortMode};
+    ///
+    /// let outliner = Outliner::new("my_outliner").with_sort_mode(SortMode::Name);
+    /// ```
+    pub fn with_sort_mode(mut self, sort_mode: SortMode) -> Self {
+        self.sort_mode = sort_mode;
+        self
+    }
+
+    /// Enables or disables hover tooltips on action icons.
+    ///
+    /// Built-in icons describe what clicking them does ("Hide", "Lock", ...),
+    /// custom icons show their `tooltip`, and
+    /// [`OutlinerNode::action_tooltip`] can override either.
+    ///
+    /// Default: enabled
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_arbor::Outliner;
+    ///
+    /// let outliner = Outliner::new("my_outliner").with_action_tooltips(false);
+    /// ```
+    pub fn with_action_tooltips(mut self, enabled: bool) -> Self {
+        self.action_tooltips = enabled;
+        self
+    }
+
+    /// Shows the number of descendants on collapsed collections.
+    ///
+    /// The count is painted as a [`Decoration::Count`] before the node's own
+    /// [`decorations`](OutlinerNode::decorations).
+    ///
+    /// Default: disabled
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_arbor::Outliner;
+    ///
+    /// let outliner = Outliner::new("my_outliner").with_descendant_counts(true);
+    /// ```
+    pub fn with_descendant_counts(mut self, enabled: bool) -> Self {
+        self.descendant_counts = enabled;
+        self
+    }
+
+    /// Sets whether a secondary (right) click cancels an ongoing drag.
+    ///
+    /// Escape always cancels a drag.
+    ///
+    /// Default: enabled
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_arbor::Outliner;
+    ///
+    /// let outliner = Outliner::new("my_outliner").with_secondary_click_cancels_drag(false);
+    /// ```
+    pub fn with_secondary_click_cancels_drag(mut self, enabled: bool) -> Self {
+        self.secondary_click_cancels_drag = enabled;
+        self
+    }
+
+    /// Sets which part of a row starts a drag.
+    ///
+    /// [`DragMode::Handle`] adds a grip after each label so that clicks on the
+    /// label never turn into drags; [`DragMode::Disabled`] turns dragging off.
+    ///
+    /// Default: [`DragMode::Label`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_arbor::{DragMode, Outliner};
+    ///
+    /// let outliner = Outliner::new("my_outliner").with_drag_mode(DragMode::Disabled);
+    /// ```
+    pub fn with_drag_mode(mut self, mode: DragMode) -> Self {
+        self.drag_mode = mode;
+        self
+    }
+
+    /// Sets how far the pointer must move, and how long the button must be
+    /// held, before pressing a row starts a drag.
+    ///
+    /// Both conditions must be met. A press released before that counts as a
+    /// click, which keeps small pointer jitter (e.g. from a pen tablet) from
+    /// swallowing selection clicks. egui's own click tolerance
+    /// ([`InputOptions::max_click_dist`](egui::InputOptions::max_click_dist))
+    /// still applies, so smaller distances have no effect.
+    ///
+    /// Default: `0.0` and `0.0` (egui's drag detection)
+    ///
+    /// # Arguments
+    ///
+    /// * `distance` - Minimum pointer movement in points
+    /// * `delay` - Minimum press duration in seconds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_arbor::Outliner;
+    ///
+    /// let outliner = Outliner::new("my_outliner").with_drag_threshold(12.0, 0.15);
+    /// ```
+    pub fn with_drag_threshold(mut self, distance: f32, delay: f32) -> Self {
+        self.drag_threshold = distance;
+        self.drag_delay = delay;
+        self
+    }
+
+    /// Sets how inline renaming normalizes names and when it commits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_arbor::{Outliner, RenameOptions};
+    ///
+    /// let outliner = Outliner::new("my_outliner")
+    ///     .with_rename_options(RenameOptions::default().with_commit_on_blur(false));
+    /// ```
+    pub fn with_rename_options(mut self, options: RenameOptions) -> Self {
+        self.rename_options = options;
+        self
+    }
+
+    /// Starts inline renaming of a node, as if it had been double-clicked.
+    ///
+    /// The rename begins the next time this outliner is shown: the node's
+    /// ancestors are expanded, its current name is put in the text field and a
+    /// [`OutlinerEvent::RenameStarted`] is reported. Requests for IDs that are not
+    /// in the tree are ignored. Each outliner edits independently, so this only
+    /// affects the outliner with this widget's ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The egui context holding the outliner's state
+    /// * `id` - The ID of the node to rename
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// if ui.button("Rename").clicked() {
+    ///     Outliner::new("scene").begin_rename(ui.ctx(), selected_id);
+    /// }
+    /// ```
+    pub fn begin_rename<Id>(&self, ctx: &egui::Context, id: Id)
+    where
+        Id: std::hash::Hash + Eq + Clone + Send + Sync + 'static,
+    {
+        let mut state = OutlinerState::<Id>::load(ctx, self.id);
+        state.request_rename(id);
+        state.store(ctx, self.id);
+    }
+
     /// Renders the outliner widget and returns the response.
     ///
     /// This is the main entry point for using the outliner. It renders all nodes
@@ -149,9 +532,57 @@ impl Outliner {
         // Load state from previous frame
         let mut state = OutlinerState::load(ui.ctx(), self.id);
 
+        // Start a rename requested through `begin_rename`, revealing the node
+        let mut rename_started = None;
+        if let Some(id) = state.take_pending_rename()
+            && let Some(node) = Self::find_node_by_id_impl(nodes, &id)
+        {
+            Self::expand_ancestors(nodes, &id, &mut state);
+            state.start_editing(id.clone(), node.name().to_string());
+            rename_started = Some(id);
+        }
+
+        // Escape (or a secondary click, if enabled) cancels an ongoing drag.
+        // egui consumes Escape itself and clears the drag payload, so losing
+        // the payload cancels the drag too.
+        let mut cancel_event = None;
+        if state.drag_drop().is_dragging()
+            && (!self.owns_drag_payload::<N::Id>(ui.ctx())
+                || ui.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::Escape))
+                || (self.secondary_click_cancels_drag
+                    && ui.input(|i| i.pointer.button_pressed(egui::PointerButton::Secondary))))
+        {
+            cancel_event = Some(Self::cancel_drag(&mut state));
+        }
+
+        // Outside the outliner nothing is a drop target, and releasing there
+        // hands the nodes to whatever is under the pointer
+        let mut dropped_outside = None;
+        let outliner_rect = ui.clip_rect().intersect(ui.available_rect_before_wrap());
+        if state.drag_drop().is_dragging()
+            && let Some(pointer) = ui.input(|i| i.pointer.latest_pos())
+            && !outliner_rect.contains(pointer)
+        {
+            state.drag_drop_mut().clear_hover();
+            if ui.input(|i| i.pointer.primary_released()) {
+                let nodes = state.dragging_nodes().to_vec();
+                Self::abandon_drag(&mut state);
+                cancel_event = Some(OutlinerEvent::DroppedOutside { nodes: nodes.clone(), pointer });
+                dropped_outside = Some(DragPayload { outliner: self.id, nodes, pointer });
+            }
+        }
+
+        // Forget the target of files that were dragged away from the outliner
+        if !state.drag_drop().is_dragging()
+            && state.drag_drop().current_drop_position().is_some()
+            && ui.input(|i| i.raw.hovered_files.is_empty())
+        {
+            state.drag_drop_mut().clear_hover();
+        }
+
         // Collect all visible node IDs in order for range selection
         let mut visible_nodes = Vec::new();
-        Self::collect_visible_node_ids(nodes, &state, &mut visible_nodes);
+        Self::collect_visible_node_ids(nodes, &state, self.sort_mode, actions, &mut visible_nodes);
 
         // Render within a scroll area and capture the inner response
         let scroll_output = egui::ScrollArea::vertical()
@@ -170,16 +601,72 @@ impl Outliner {
                 let mut outliner_response = OutlinerResponse::new(
                     ui.allocate_response(egui::vec2(ui.available_width(), 0.0), egui::Sense::hover())
                 );
+                if let Some(id) = rename_started {
+                    outliner_response.push_event(OutlinerEvent::RenameStarted(id));
+                }
+                if let Some(event) = cancel_event {
+                    outliner_response.push_event(event);
+                }
 
                 // Render all root nodes
-                for node in nodes {
+                for node in self.sort_mode.sorted(nodes, actions) {
                     self.render_node(ui, node, 0, nodes, &mut state, actions, &mut outliner_response, &visible_nodes, &mut node_rects, &selected_nodes);
                 }
 
                 // Handle box selection in the background
-                let available_rect = ui.available_rect_before_wrap();
+                let mut available_rect = ui.available_rect_before_wrap();
+                let file_drag = Self::file_drag(ui);
+                if state.drag_drop().is_dragging() || file_drag.is_some() {
+                    // Keep at least one row of empty space to drop into
+                    available_rect.max.y = available_rect.max.y.max(available_rect.top() + self.style.row_height);
+                }
                 let bg_response = ui.allocate_rect(available_rect, egui::Sense::click_and_drag());
 
+                // Dropping into the empty space below the tree appends at the
+                // root level; this has no target node, so it works for empty trees
+                if state.drag_drop().is_dragging() {
+                    let still_pressed = ui.input(|i| i.pointer.primary_down());
+                    let over_empty_space = ui.ctx().pointer_hover_pos().is_some_and(|pos| {
+                        ui.clip_rect().intersect(available_rect).contains(pos)
+                            && !node_rects.iter().any(|(_, rect)| rect.contains(pos))
+                    });
+                    if still_pressed && over_empty_space {
+                        match Self::can_drop(nodes, &state, actions, None, DropPosition::End) {
+                            Ok(()) => {
+                                if state.drag_drop().current_drop_position() != Some(DropPosition::End) {
+                                    outliner_response.push_event(OutlinerEvent::DragHovered {
+                                        target: None,
+                                        position: DropPosition::End,
+                                    });
+                                }
+                                state.drag_drop_mut().hover_end();
+                            }
+                            Err(reason) => state.drag_drop_mut().reject_end(reason),
+                        }
+                    }
+                } else if let Some((pointer, dropped)) = file_drag
+                    && ui.clip_rect().intersect(available_rect).contains(pointer)
+                    && !node_rects.iter().any(|(_, rect)| rect.contains(pointer))
+                {
+                    // Files from the operating system can be appended at the root level too
+                    if dropped.is_empty() {
+                        state.drag_drop_mut().hover_end();
+                    } else {
+                        state.drag_drop_mut().clear_hover();
+                        outliner_response.push_event(OutlinerEvent::FilesDropped {
+                            target: None,
+                            position: DropPosition::End,
+                            files: dropped,
+                        });
+                    }
+                }
+
+                if state.drag_drop().current_drop_position() == Some(DropPosition::End) {
+                    self.drag_drop_visuals.draw_end_drop(ui.painter(), available_rect);
+                } else if state.drag_drop().rejection().is_some_and(|(_, position, _)| position == DropPosition::End) {
+                    self.drag_drop_visuals.for_invalid_target().draw_end_drop(ui.painter(), available_rect);
+                }
+
                 // Check if we're starting a box selection (clicking in empty space)
                 if bg_response.drag_started()
                     && let Some(start_pos) = ui.ctx().pointer_interact_pos() {
@@ -204,21 +691,19 @@ impl Outliner {
                     );
 
                     // Draw the selection box
+                    let radius = self.style.box_selection_corner_radius;
                     ui.painter().rect_stroke(
                         selection_rect,
-                        0.0,
-                        egui::Stroke::new(1.0, egui::Color32::from_rgb(100, 150, 255)),
+                        radius,
+                        self.style.box_selection_stroke,
                         egui::epaint::StrokeKind::Outside,
                     );
-                    ui.painter().rect_filled(
-                        selection_rect,
-                        0.0,
-                        egui::Color32::from_rgba_premultiplied(100, 150, 255, 30),
-                    );
+                    ui.painter().rect_filled(selection_rect, radius, self.style.box_selection_fill);
 
                     // Update selection based on box
                     if bg_response.dragged() {
                         let ctrl_or_cmd_pressed = ui.input(|i| i.modifiers.command || i.modifiers.ctrl);
+                        let before = Self::selected_ids(&visible_nodes, actions);
                         
                         // If not holding ctrl/cmd, deselect all first
                         if !ctrl_or_cmd_pressed {
@@ -235,7 +720,11 @@ impl Outliner {
                                 actions.on_select(node_id, true);
                             }
                         }
-                        outliner_response.changed = true;
+
+                        let after = Self::selected_ids(&visible_nodes, actions);
+                        if let Some(event) = OutlinerEvent::selection_change(&before, &after) {
+                            outliner_response.push_event(event);
+                        }
                     }
                 }
 
@@ -246,43 +735,433 @@ impl Outliner {
                 outliner_response
             });
 
-        // Store state for next frame
-        state.store(ui.ctx(), self.id);
+        let mut outliner_response = scroll_output.inner;
 
-        scroll_output.inner
-    }
+        // Keyboard shortcuts only apply while the pointer is over the outliner
+        // and no text field (e.g. an inline rename) owns the keyboard
+        let keyboard_active = ui.rect_contains_pointer(scroll_output.inner_rect)
+            && !ui.ctx().wants_keyboard_input();
 
-    /// Collects all visible node IDs in order (depth-first traversal).
-    ///
-    /// This is used for shift-click range selection.
-    fn collect_visible_node_ids<N>(
-        nodes: &[N],
-        state: &OutlinerState<N::Id>,
-        result: &mut Vec<N::Id>,
-    ) where
-        N: OutlinerNode,
-    {
-        for node in nodes {
-            result.push(node.id());
-            if node.is_collection() && state.is_expanded(&node.id()) {
-                Self::collect_visible_node_ids(node.children(), state, result);
+        if keyboard_active
+            && let Some(shortcut) = &self.duplicate_shortcut
+            && ui.input_mut(|i| i.consume_shortcut(shortcut))
+        {
+            let mut selected_roots = Vec::new();
+            Self::collect_selected_roots(nodes, actions, &mut selected_roots);
+            if !selected_roots.is_empty() {
+                outliner_response.push_event(OutlinerEvent::DuplicateRequested(selected_roots));
             }
         }
-    }
 
-    /// Renders a single node and its children recursively.
-    ///
-    /// This method handles the complete rendering of a node including:
-    /// - Indentation based on depth
-    /// - Expand/collapse arrow (for collections)
-    /// - Node icon (if provided)
-    /// - Node label (clickable, editable)
-    /// - Action icons
-    /// - Recursive rendering of children (if expanded)
-    #[allow(clippy::too_many_arguments)]
-    fn render_node<N, A>(
-        &self,
-        ui: &mut egui::Ui,
+        if keyboard_active
+            && self.delete_enabled
+            && state.pending_delete().is_empty()
+            && ui.input_mut(|i| {
+                i.consume_key(egui::Modifiers::NONE, egui::Key::Delete)
+                    || i.consume_key(egui::Modifiers::NONE, egui::Key::Backspace)
+            })
+        {
+            let mut ids = Vec::new();
+            Self::collect_selected_roots(nodes, actions, &mut ids);
+
+            let needs_confirmation = self.confirm_delete
+                && ids.iter().any(|id| {
+                    Self::find_node_by_id_impl(nodes, id)
+                        .is_some_and(|node| !node.children().is_empty())
+                });
+
+            if needs_confirmation {
+                state.set_pending_delete(ids);
+            } else if !ids.is_empty() {
+                actions.on_delete(&Self::with_descendant_ids(nodes, &ids));
+                outliner_response.push_event(OutlinerEvent::DeleteRequested(ids));
+            }
+        }
+
+        if !state.pending_delete().is_empty()
+            && let Some(confirmed) = self.show_delete_confirmation(
+                ui,
+                nodes,
+                state.pending_delete(),
+                scroll_output.inner_rect,
+            )
+        {
+            let ids = state.take_pending_delete();
+            if confirmed {
+                actions.on_delete(&Self::with_descendant_ids(nodes, &ids));
+                outliner_response.push_event(OutlinerEvent::DeleteRequested(ids));
+            }
+        }
+
+        if keyboard_active
+            && self.clipboard_enabled
+            && let Some(command) = self.read_clipboard_command(ui, nodes, &state, actions)
+        {
+            outliner_response.push_event(OutlinerEvent::Clipboard(command));
+        }
+
+        // Show what is being dragged, and what dropping it would do
+        if state.drag_drop().is_dragging() {
+            let operation = DropOperation::from_modifiers(ui.input(|i| i.modifiers));
+            let rejection = state.drag_drop().rejection().map(|(_, _, reason)| reason.to_string());
+            ui.ctx().set_cursor_icon(if rejection.is_some() {
+                egui::CursorIcon::NotAllowed
+            } else {
+                operation.cursor_icon()
+            });
+
+            if let Some(pointer) = ui.ctx().pointer_hover_pos() {
+                if self.drag_drop_visuals.show_preview {
+                    self.show_drag_preview(ui, nodes, &state, actions, operation, pointer);
+                }
+                if let Some(reason) = rejection {
+                    self.show_drop_rejection(ui, &reason, pointer);
+                }
+            }
+        }
+
+        // A drag whose release no row handled (e.g. because the dragged row
+        // disappeared) must not outlive the press
+        if state.drag_drop().is_dragging() && !ui.input(|i| i.pointer.primary_down()) {
+            outliner_response.push_event(Self::cancel_drag(&mut state));
+        }
+
+        // Publish the drag so other panels can accept the nodes. After a drop
+        // outside, the final payload stays until egui clears it at the end of
+        // the frame, so panels shown later in the frame can still take it.
+        if state.drag_drop().is_dragging() {
+            let pointer = ui.input(|i| i.pointer.latest_pos()).unwrap_or_default();
+            egui::DragAndDrop::set_payload(ui.ctx(), DragPayload {
+                outliner: self.id,
+                nodes: state.dragging_nodes().to_vec(),
+                pointer,
+            });
+        } else if let Some(payload) = dropped_outside {
+            egui::DragAndDrop::set_payload(ui.ctx(), payload);
+        } else if self.owns_drag_payload::<N::Id>(ui.ctx()) {
+            egui::DragAndDrop::clear_payload(ui.ctx());
+        }
+
+        // Store state for next frame
+        state.store(ui.ctx(), self.id);
+
+        outliner_response
+    }
+
+    /// Returns whether egui's drag-and-drop payload is this outliner's drag.
+    fn owns_drag_payload<Id>(&self, ctx: &egui::Context) -> bool
+    where
+        Id: Send + Sync + 'static,
+    {
+        egui::DragAndDrop::payload::<DragPayload<Id>>(ctx).is_some_and(|payload| payload.outliner == self.id)
+    }
+
+    /// Shows the floating preview of the dragged nodes next to the pointer.
+    ///
+    /// The default preview marks copies and links (see
+    /// [`DragDropVisuals::preview_text`]).
+    fn show_drag_preview<N, A>(
+        &self,
+        ui: &egui::Ui,
+        nodes: &[N],
+        state: &OutlinerState<N::Id>,
+        actions: &mut A,
+        operation: DropOperation,
+        pointer: egui::Pos2,
+    ) where
+        N: OutlinerNode,
+        A: OutlinerActions<N>,
+    {
+        let dragged: Vec<&N> = state
+            .dragging_nodes()
+            .iter()
+            .filter_map(|id| Self::find_node_by_id_impl(nodes, id))
+            .collect();
+        if dragged.is_empty() {
+            return;
+        }
+
+        let visuals = &self.drag_drop_visuals;
+        egui::Area::new(self.id.with("drag_preview"))
+            .order(egui::Order::Tooltip)
+            .interactable(false)
+            .fixed_pos(pointer + visuals.preview_offset)
+            .show(ui.ctx(), |ui| {
+                ui.set_opacity(visuals.preview_opacity);
+
+                if actions.has_drag_preview() {
+                    actions.drag_preview_ui(ui, &dragged);
+                    return;
+                }
+
+                let mut frame = egui::Frame::popup(ui.style());
+                if let Some(fill) = visuals.preview_fill {
+                    frame = frame.fill(fill);
+                }
+                frame.show(ui, |ui| {
+                    let mut text = egui::RichText::new(DragDropVisuals::preview_text(&dragged, operation));
+                    if let Some(color) = visuals.preview_text_color {
+                        text = text.color(color);
+                    }
+                    ui.add(egui::Label::new(text).extend());
+                });
+            });
+    }
+
+    /// Shows why the hovered drop was rejected, just above the pointer.
+    fn show_drop_rejection(&self, ui: &egui::Ui, reason: &str, pointer: egui::Pos2) {
+        egui::Area::new(self.id.with("drop_rejection"))
+            .order(egui::Order::Tooltip)
+            .interactable(false)
+            .pivot(egui::Align2::LEFT_BOTTOM)
+            .fixed_pos(pointer + egui::vec2(self.drag_drop_visuals.preview_offset.x, -4.0))
+            .show(ui.ctx(), |ui| {
+                let color = self.style.error_color.unwrap_or(ui.visuals().error_fg_color);
+                egui::Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.add(egui::Label::new(egui::RichText::new(reason).color(color)).extend());
+                });
+            });
+    }
+
+    /// Asks [`OutlinerActions::can_drop`] whether the dragged nodes may be
+    /// dropped relative to `target` (`None` for [`DropPosition::End`]).
+    fn can_drop<N, A>(
+        all_nodes: &[N],
+        state: &OutlinerState<N::Id>,
+        actions: &A,
+        target: Option<&N>,
+        position: DropPosition,
+    ) -> Result<(), String>
+    where
+        N: OutlinerNode,
+        A: OutlinerActions<N>,
+    {
+        let sources: Vec<&N> = state
+            .dragging_nodes()
+            .iter()
+            .filter_map(|id| Self::find_node_by_id_impl(all_nodes, id))
+            .collect();
+        actions.can_drop(&sources, target, position)
+    }
+
+    /// Shows the delete confirmation popup centered over the outliner.
+    ///
+    /// Returns `Some(true)` when the user confirms, `Some(false)` when they cancel,
+    /// and `None` while the popup is still open. Enter and Escape only answer
+    /// the popup while one of its buttons has keyboard focus.
+    fn show_delete_confirmation<N>(
+        &self,
+        ui: &egui::Ui,
+        nodes: &[N],
+        pending: &[N::Id],
+        outliner_rect: egui::Rect,
+    ) -> Option<bool>
+    where
+        N: OutlinerNode,
+    {
+        let total: usize = pending
+            .iter()
+            .filter_map(|id| Self::find_node_by_id_impl(nodes, id))
+            .map(|node| 1 + Self::collect_descendant_ids(node).len())
+            .sum();
+
+        // egui drops focus on Escape before widgets run, so remember whether
+        // the popup had it last frame
+        let focus_id = self.id.with("delete_confirmation_focus");
+        let had_focus = ui.ctx().data(|d| d.get_temp::<bool>(focus_id)).unwrap_or(false);
+
+        let mut result = None;
+        let mut has_focus = false;
+        egui::Window::new("Delete")
+            .id(self.id.with("delete_confirmation"))
+            .collapsible(false)
+            .resizable(false)
+            .pivot(egui::Align2::CENTER_CENTER)
+            .fixed_pos(outliner_rect.center())
+            .show(ui.ctx(), |ui| {
+                ui.label(format!(
+                    "Delete {} item(s), including collection contents?",
+                    total
+                ));
+                ui.horizontal(|ui| {
+                    // Focusing Delete lets Enter confirm, without taking the
+                    // keyboard from a text field the user is typing in
+                    let delete = ui.button("Delete");
+                    if ui.memory(|m| m.focused().is_none()) {
+                        delete.request_focus();
+                    }
+                    let cancel = ui.button("Cancel");
+                    if delete.clicked() {
+                        result = Some(true);
+                    }
+                    if cancel.clicked() {
+                        result = Some(false);
+                    }
+                    has_focus = delete.has_focus() || cancel.has_focus();
+                });
+            });
+
+        if result.is_none() && had_focus && ui.input(|i| i.key_pressed(egui::Key::Escape)) {
+            result = Some(false);
+        }
+        ui.ctx().data_mut(|d| d.insert_temp(focus_id, has_focus && result.is_none()));
+
+        result
+    }
+
+    /// Turns this frame's cut/copy/paste input into a clipboard command.
+    ///
+    /// Integrations such as eframe deliver these shortcuts as dedicated
+    /// [`egui::Event`]s rather than key presses, so both forms are accepted.
+    fn read_clipboard_command<N, A>(
+        &self,
+        ui: &egui::Ui,
+        nodes: &[N],
+        state: &OutlinerState<N::Id>,
+        actions: &A,
+    ) -> Option<ClipboardCommand<N::Id>>
+    where
+        N: OutlinerNode,
+        A: OutlinerActions<N>,
+    {
+        let (copy, cut, paste) = ui.input(|i| {
+            let mut copy = false;
+            let mut cut = false;
+            let mut paste: Option<Option<String>> = None;
+            for event in &i.events {
+                match event {
+                    egui::Event::Copy => copy = true,
+                    egui::Event::Cut => cut = true,
+                    egui::Event::Paste(text) => paste = Some(Some(text.clone())),
+                    egui::Event::Key { key, pressed: true, modifiers, .. } if modifiers.command => {
+                        match key {
+                            egui::Key::C => copy = true,
+                            egui::Key::X => cut = true,
+                            egui::Key::V if paste.is_none() => paste = Some(None),
+                            _ => {}
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            (copy, cut, paste)
+        });
+
+        if cut || copy {
+            let mut ids = Vec::new();
+            Self::collect_selected_roots(nodes, actions, &mut ids);
+            if ids.is_empty() {
+                return None;
+            }
+
+            // With serde, NodeClipboard::apply_with_system_clipboard exports
+            // the serialized clipboard; without it only the names are offered
+            #[cfg(feature = "serde")]
+            ui.ctx().data_mut(|d| {
+                d.insert_temp(crate::clipboard::system_clipboard_request_id(), self.system_clipboard)
+            });
+            #[cfg(not(feature = "serde"))]
+            if self.system_clipboard {
+                let names: Vec<&str> = ids
+                    .iter()
+                    .filter_map(|id| Self::find_node_by_id_impl(nodes, id))
+                    .map(|node| node.name())
+                    .collect();
+                ui.ctx().copy_text(names.join("\n"));
+            }
+
+            return Some(if cut {
+                ClipboardCommand::Cut(ids)
+            } else {
+                ClipboardCommand::Copy(ids)
+            });
+        }
+
+        let text = paste?;
+        // A cut node can stay selected after the application removed it
+        let target = state
+            .last_selected()
+            .filter(|id| actions.is_selected(id) && Self::find_node_by_id_impl(nodes, id).is_some())
+            .cloned();
+        let position = match &target {
+            Some(id) if self.paste_position == DropPosition::Inside => {
+                match Self::find_node_by_id_impl(nodes, id) {
+                    Some(node) if node.is_collection() => DropPosition::Inside,
+                    _ => DropPosition::After,
+                }
+            }
+            _ => self.paste_position,
+        };
+
+        Some(ClipboardCommand::Paste {
+            target,
+            position,
+            text,
+        })
+    }
+
+    /// Collects the IDs of selected nodes whose ancestors are not selected.
+    ///
+    /// Operations on a selection (such as duplicating) act on whole subtrees, so a
+    /// selected node inside a selected collection is covered by its ancestor.
+    fn collect_selected_roots<N, A>(nodes: &[N], actions: &A, result: &mut Vec<N::Id>)
+    where
+        N: OutlinerNode,
+        A: OutlinerActions<N>,
+    {
+        for node in nodes {
+            let id = node.id();
+            if actions.is_selected(&id) {
+                result.push(id);
+            } else {
+                Self::collect_selected_roots(node.children(), actions, result);
+            }
+        }
+    }
+
+    /// Returns the IDs from `ids` that are currently selected, in order.
+    fn selected_ids<N, A>(ids: &[N::Id], actions: &A) -> Vec<N::Id>
+    where
+        N: OutlinerNode,
+        A: OutlinerActions<N>,
+    {
+        ids.iter().filter(|id| actions.is_selected(id)).cloned().collect()
+    }
+
+    /// Collects all visible node IDs in order (depth-first traversal).
+    ///
+    /// This is used for shift-click range selection.
+    fn collect_visible_node_ids<N, A>(
+        nodes: &[N],
+        state: &OutlinerState<N::Id>,
+        sort_mode: SortMode,
+        actions: &A,
+        result: &mut Vec<N::Id>,
+    ) where
+        N: OutlinerNode,
+        A: OutlinerActions<N>,
+    {
+        for node in sort_mode.sorted(nodes, actions) {
+            result.push(node.id());
+            if node.is_collection() && state.is_expanded(&node.id()) {
+                Self::collect_visible_node_ids(node.children(), state, sort_mode, actions, result);
+            }
+        }
+    }
+
+    /// Renders a single node and its children recursively.
+    ///
+    /// This method handles the complete rendering of a node including:
+    /// - Indentation based on depth
+    /// - Expand/collapse arrow (for collections)
+    /// - Node icon (if provided)
+    /// - Node label (clickable, editable)
+    /// - Action icons
+    /// - Recursive rendering of children (if expanded)
+    #[allow(clippy::too_many_arguments)]
+    fn render_node<N, A>(
+        &self,
+        ui: &mut egui::Ui,
         node: &N,
         depth: usize,
         all_nodes: &[N],
@@ -302,11 +1181,16 @@ impl Outliner {
         let is_editing = state.is_editing(&node_id);
         let is_selected = actions.is_selected(&node_id);
 
-        // Check drag-drop state
-        let is_dragging = state.drag_drop().is_dragging_node(&node_id);
+        // Check drag-drop state; every node of a multi-drag counts as dragged
+        let is_dragging = state.drag_drop().is_dragging_node(&node_id) || state.dragging_nodes().contains(&node_id);
         let is_hover_target = state.drag_drop().is_hover_target(&node_id);
         let drop_position = state.drag_drop().current_drop_position();
 
+        // Reserve a shape behind the row for full-row highlights and striping,
+        // filled in once the row's rect is known
+        let row_index = node_rects.len();
+        let row_background = ui.painter().add(egui::Shape::Noop);
+
         // Start horizontal layout for this row
         let row_output = ui.horizontal(|ui| {
             // Calculate space needed for action icons upfront
@@ -318,10 +1202,15 @@ impl Outliner {
 
             // Render expand/collapse arrow for collections
             if is_collection {
-                let expand_response = self.render_expand_icon(ui, is_expanded);
+                let openness = self.expand_openness(ui.ctx(), &node_id, is_expanded);
+                let expand_response = self.render_expand_icon(ui, openness);
                 if expand_response.clicked() {
                     state.toggle_expanded(&node_id);
-                    response.changed = true;
+                    response.push_event(if is_expanded {
+                        OutlinerEvent::Collapsed(node_id.clone())
+                    } else {
+                        OutlinerEvent::Expanded(node_id.clone())
+                    });
                 }
             } else {
                 // Add spacing to align with non-collection nodes
@@ -334,24 +1223,63 @@ impl Outliner {
                 ui.add_space(self.style.icon_spacing);
             }
 
+            // Space requested by the node's inline widget, if any
+            let widget_width = actions.row_widget_width(node).max(0.0);
+            let mut reserved_width = if widget_width > 0.0 {
+                icons_width + widget_width + self.style.icon_spacing
+            } else {
+                icons_width
+            };
+            let show_handle = self.drag_mode == DragMode::Handle && !is_editing;
+            if show_handle {
+                reserved_width += self.style.expand_icon_size + self.style.icon_spacing;
+            }
+
             // Render node label (or text edit if editing)
             let label_response = self.render_node_label(
                 ui,
                 node,
                 is_editing,
                 is_selected,
-                icons_width,
+                reserved_width,
                 state,
                 actions,
                 response,
             );
 
+            // In handle mode, only the grip after the label starts drags
+            let handle_response = show_handle.then(|| self.render_drag_handle(ui));
+
+            // Render the inline widget between the label and the action icons.
+            // It gets its own area, so interacting with it doesn't select or drag the row
+            if widget_width > 0.0 {
+                let row = RowInfo {
+                    depth,
+                    is_selected,
+                    is_expanded,
+                    is_editing,
+                    width: widget_width,
+                };
+                ui.allocate_ui_with_layout(
+                    egui::vec2(widget_width, self.style.row_height),
+                    egui::Layout::left_to_right(egui::Align::Center),
+                    |ui| {
+                        ui.set_max_width(widget_width);
+                        actions.row_widget_ui(ui, node, &row);
+                    },
+                );
+            }
+
             // Handle label interactions
             if !is_editing {
-                if label_response.clicked() {
+                // A press that moved without reaching the drag threshold is a click
+                let clicked = label_response.clicked()
+                    || (label_response.drag_stopped() && state.is_drag_armed(&node_id));
+                if clicked {
                     // Check for modifier keys
                     let shift_pressed = ui.input(|i| i.modifiers.shift);
                     let ctrl_or_cmd_pressed = ui.input(|i| i.modifiers.command || i.modifiers.ctrl);
+                    let before = Self::selected_ids(visible_nodes, actions);
 
                     if shift_pressed && state.last_selected().is_some() {
                         // Shift-click: select range
@@ -395,43 +1323,78 @@ impl Outliner {
                         response.selected = Some(node_id.clone());
                         response.changed = true;
                     }
+
+                    let after = Self::selected_ids(visible_nodes, actions);
+                    if let Some(event) = OutlinerEvent::selection_change(&before, &after) {
+                        response.push_event(event);
+                    }
                 }
 
                 if label_response.double_clicked() {
                     state.start_editing(node_id.clone(), node.name().to_string());
-                    response.double_clicked = Some(node_id.clone());
-                    response.changed = true;
+                    response.push_event(OutlinerEvent::DoubleClicked(node_id.clone()));
+                    response.push_event(OutlinerEvent::RenameStarted(node_id.clone()));
                 }
 
                 if label_response.secondary_clicked() {
-                    response.context_menu = Some(node_id.clone());
+                    response.push_event(OutlinerEvent::ContextMenu(node_id.clone()));
                 }
             }
 
             // Render action icons (right-aligned)
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                self.render_action_icons(ui, node, actions);
+                self.render_action_icons(ui, node, actions, response);
             });
 
-            // Return the label response so we can use it for drag detection
-            label_response
+            // Return the response that starts drags, and where the label is
+            let label_rect = label_response.rect;
+            (handle_response.unwrap_or(label_response), label_rect)
         });
 
         let row_rect = row_output.response.rect;
-        let label_response = row_output.inner;
+        let (drag_response, label_rect) = row_output.inner;
 
         // Store the node rectangle for box selection
         node_rects.push((node_id.clone(), row_rect));
 
-        // Use the label response for drag detection
-        let drag_response = label_response;
+        let is_row_hovered = ui.rect_contains_pointer(row_rect);
+        if is_row_hovered {
+            response.hovered = Some(node_id.clone());
+        }
+
+        let tint = node.label_style().background.filter(|_| !is_editing).map(|color| (label_rect, color));
+        let background = self.row_background_shapes(ui, row_rect, row_index, tint, is_selected, is_row_hovered && !is_editing);
+        ui.painter().set(row_background, background);
+
+        // Outline the active (most recently selected) row
+        if let Some(stroke) = self.style.focus_outline
+            && is_selected
+            && state.last_selected() == Some(&node_id)
+        {
+            ui.painter().rect_stroke(row_rect, self.style.corner_radius, stroke, egui::epaint::StrokeKind::Inside);
+        }
 
         // Handle drag-drop interactions
         if !is_editing {
-            // Detect drag start
-            if drag_response.drag_started() {
+            // Detect drag start, waiting for the drag threshold if one is set
+            let start_drag = match self.drag_mode {
+                DragMode::Disabled => false,
+                _ if self.drag_threshold <= 0.0 && self.drag_delay <= 0.0 => drag_response.drag_started(),
+                _ => {
+                    if drag_response.drag_started() {
+                        state.arm_drag(node_id.clone());
+                    }
+                    let armed = state.is_drag_armed(&node_id) && drag_response.dragged();
+                    if armed {
+                        ui.ctx().request_repaint();
+                    }
+                    armed && self.drag_threshold_reached(ui)
+                }
+            };
+
+            if start_drag {
+                state.disarm_drag();
                 state.drag_drop_mut().start_drag(node_id.clone());
-                response.drag_started = Some(node_id.clone());
                 
                 // Collect all selected nodes for multi-drag
                 // If the dragged node is selected, include all selected nodes
@@ -443,33 +1406,80 @@ impl Outliner {
                 };
                 
                 state.set_dragging_nodes(dragging_nodes.clone());
-                response.dragging_nodes = dragging_nodes;
-                response.changed = true;
+                response.push_event(OutlinerEvent::DragStarted {
+                    id: node_id.clone(),
+                    nodes: dragging_nodes,
+                });
             }
 
             // Handle hover for drop target detection
             if state.drag_drop().is_dragging() && !is_dragging {
                 // Check if cursor is hovering over this row
                 if let Some(cursor_pos) = ui.ctx().pointer_hover_pos()
-                    && row_rect.contains(cursor_pos) {
-                    let position = calculate_drop_position(
+                    && ui.clip_rect().intersect(row_rect).contains(cursor_pos) {
+                    let mut position = self.drag_drop_visuals.drop_position(
                         cursor_pos.y,
                         row_rect,
                         is_collection,
                     );
 
-                    // Validate the drop
+                    // Sorted siblings have no manual order, so Before and After
+                    // both just mean "next to the target"
+                    if !self.sort_mode.is_manual() && position == DropPosition::Before {
+                        position = DropPosition::After;
+                    }
+
+                    // In indent-aware mode, the pointer's horizontal position can
+                    // outdent a drop after a last child to an ancestor's level
+                    let mut target = node;
+                    let mut line_depth = depth;
+                    let has_visible_children = is_collection && is_expanded && !node.children().is_empty();
+                    if self.drag_drop_visuals.indent_aware
+                        && position == DropPosition::After
+                        && !has_visible_children
+                    {
+                        let outdent_targets = self.outdent_targets(all_nodes, &node_id, actions);
+                        let pointer_depth = ((cursor_pos.x - row_rect.left()) / self.style.indent).max(0.0) as usize;
+                        let levels = depth.saturating_sub(pointer_depth).min(outdent_targets.len());
+                        if levels > 0 {
+                            target = outdent_targets[levels - 1];
+                            line_depth = depth - levels;
+                        }
+                    }
+                    let target_id = target.id();
+
+                    // Validate the drop against every dragged node
                     if let Some(source_id) = state.drag_drop().dragging_id() {
-                        let is_valid = validate_drop(
-                            source_id,
-                            &node_id,
-                            position,
-                            node,
-                            |target, source| Self::is_descendant_of_impl(all_nodes, target, source),
-                        );
+                        let is_valid = std::iter::once(source_id).chain(state.dragging_nodes()).all(|source_id| {
+                            validate_drop(
+                                source_id,
+                                &target_id,
+                                position,
+                                target,
+                                |target, source| Self::is_descendant_of_impl(all_nodes, target, source),
+                            )
+                        });
+
+                        // Domain rules of the application come after the built-in checks
+                        let verdict = if is_valid {
+                            Self::can_drop(all_nodes, state, actions, Some(target), position)
+                        } else {
+                            Ok(())
+                        };
 
-                        if is_valid {
-                            state.drag_drop_mut().update_hover(node_id.clone(), position);
+                        if let Err(reason) = verdict {
+                            state.drag_drop_mut().reject_hover(node_id.clone(), position, reason);
+                        } else if is_valid {
+                            if !state.drag_drop().is_hover_target(&target_id) || drop_position != Some(position) {
+                                response.push_event(OutlinerEvent::DragHovered {
+                                    target: Some(target_id.clone()),
+                                    position,
+                                });
+                            }
+                            state.drag_drop_mut().update_hover(target_id, position);
+                            if self.drag_drop_visuals.indent_aware && position != DropPosition::Inside {
+                                state.drag_drop_mut().set_drop_line(node_id.clone(), line_depth);
+                            }
                         } else {
                             state.drag_drop_mut().clear_hover();
                         }
@@ -479,24 +1489,68 @@ impl Outliner {
 
             // Handle drop
             if state.drag_drop().is_dragging() && drag_response.drag_stopped() {
-                if let Some((source_id, target_id, position)) = state.drag_drop_mut().end_drag() {
-                    // Invoke the on_move callback
-                    actions.on_move(&source_id, &target_id, position);
-                    
-                    // Get the dragging nodes and add them to the response
-                    response.dragging_nodes = state.dragging_nodes().to_vec();
-                    
-                    // Record the drop event in the response
-                    response.drop_event = Some(DropEvent::new(source_id, target_id, position));
-                    response.changed = true;
-                    
+                // Drops below the tree have no target, so note them before ending the drag
+                let at_end = state.drag_drop().current_drop_position() == Some(DropPosition::End);
+                let dragged = state.drag_drop().dragging_id().cloned();
+
+                // The modifiers held at drop time decide between move, copy and link.
+                // Copies and links are made of every dragged node at once.
+                let operation = DropOperation::from_modifiers(ui.input(|i| i.modifiers));
+                let drop_event = match state.drag_drop_mut().end_drag() {
+                    Some((source_id, target_id, position)) => {
+                        match operation {
+                            DropOperation::Move => actions.on_move(&source_id, &target_id, position),
+                            DropOperation::Copy => actions.on_copy(state.dragging_nodes(), &target_id, position),
+                            DropOperation::Link => actions.on_link(state.dragging_nodes(), &target_id, position),
+                        }
+                        Some(DropEvent::new(source_id, target_id, position))
+                    }
+                    None if at_end => dragged.map(|source_id| {
+                        actions.on_drop_at_end(state.dragging_nodes(), operation);
+                        DropEvent::at_end(source_id)
+                    }),
+                    None => None,
+                };
+
+                if let Some(event) = drop_event {
+                    // Record the drop event and the dragged nodes in the response
+                    response.push_event(OutlinerEvent::Dropped {
+                        event: event.with_operation(operation),
+                        nodes: state.dragging_nodes().to_vec(),
+                    });
+
                     // Clear dragging nodes after drop
                     state.clear_dragging_nodes();
                 } else {
-                    state.drag_drop_mut().cancel_drag();
-                    state.clear_dragging_nodes();
+                    response.push_event(Self::cancel_drag(state));
                 }
             }
+
+            if drag_response.drag_stopped() && state.is_drag_armed(&node_id) {
+                state.disarm_drag();
+            }
+        }
+
+        // Files dragged in from the operating system target rows like dragged nodes
+        if !state.drag_drop().is_dragging()
+            && let Some((pointer, dropped)) = Self::file_drag(ui)
+            && ui.clip_rect().intersect(row_rect).contains(pointer)
+        {
+            let mut position = self.drag_drop_visuals.drop_position(pointer.y, row_rect, is_collection);
+            if !self.sort_mode.is_manual() && position == DropPosition::Before {
+                position = DropPosition::After;
+            }
+
+            if dropped.is_empty() {
+                state.drag_drop_mut().update_hover(node_id.clone(), position);
+            } else {
+                state.drag_drop_mut().clear_hover();
+                response.push_event(OutlinerEvent::FilesDropped {
+                    target: Some(node_id.clone()),
+                    position,
+                    files: dropped,
+                });
+            }
         }
 
         // Draw visual feedback for drag-drop
@@ -504,7 +1558,27 @@ impl Outliner {
             self.drag_drop_visuals.draw_drag_source(ui.painter(), row_rect);
         }
 
-        if is_hover_target
+        if let Some((rejected_row, position, _)) = state.drag_drop().rejection() {
+            // Faded indicator for a drop rejected by `OutlinerActions::can_drop`
+            if rejected_row == Some(&node_id) {
+                let visuals = self.drag_drop_visuals.for_invalid_target();
+                match position {
+                    DropPosition::Before | DropPosition::After => visuals.draw_drop_line(ui.painter(), row_rect, position),
+                    DropPosition::Inside => visuals.draw_drop_highlight(ui.painter(), row_rect),
+                    DropPosition::End => {}
+                }
+            }
+        } else if let Some((line_row, line_depth)) = state.drag_drop().drop_line() {
+            // Indent-aware drop line, drawn on the hovered row even when the
+            // drop outdents to an ancestor
+            if line_row == &node_id
+                && let Some(position) = state.drag_drop().current_drop_position()
+            {
+                let mut line_rect = row_rect;
+                line_rect.min.x += line_depth as f32 * self.style.indent;
+                self.drag_drop_visuals.draw_drop_line(ui.painter(), line_rect, position);
+            }
+        } else if is_hover_target
             && let Some(position) = drop_position {
                 match position {
                     DropPosition::Before | DropPosition::After => {
@@ -513,54 +1587,367 @@ impl Outliner {
                     DropPosition::Inside => {
                         self.drag_drop_visuals.draw_drop_highlight(ui.painter(), row_rect);
                     }
+                    // Drawn over the empty area below the tree instead
+                    DropPosition::End => {}
                 }
             }
 
-        // Render children if this is an expanded collection
-        if is_collection && is_expanded {
-            for child in node.children() {
-                self.render_node(ui, child, depth + 1, all_nodes, state, actions, response, visible_nodes, node_rects, selected_nodes);
+        // Render children if this is an expanded collection (or one that is
+        // still animating open or closed)
+        let openness = if is_collection { self.expand_openness(ui.ctx(), &node_id, is_expanded) } else { 0.0 };
+        if openness > 0.0 {
+            let first_descendant = node_rects.len();
+            let mut render_children = |ui: &mut egui::Ui, node_rects: &mut Vec<(N::Id, egui::Rect)>| {
+                for child in self.sort_mode.sorted(node.children(), actions) {
+                    self.render_node(ui, child, depth + 1, all_nodes, state, actions, response, visible_nodes, node_rects, selected_nodes);
+                }
+            };
+
+            if openness < 1.0 {
+                // Render all children into a child UI clipped to the animated
+                // height, then only allocate that height in the outliner. Rows
+                // outside the clip rect are neither visible nor hoverable.
+                let height_id = self.id.with("expand_height").with(&node_id);
+                let full_height = ui.ctx().data(|d| d.get_temp::<f32>(height_id)).unwrap_or_else(|| {
+                    node.children().len() as f32 * (self.style.row_height + ui.spacing().item_spacing.y)
+                });
+                let visible_height = (full_height * openness).round();
+
+                let rect = ui.available_rect_before_wrap();
+                let mut child_ui = ui.new_child(egui::UiBuilder::new().max_rect(rect).layout(*ui.layout()));
+                let mut clip_rect = child_ui.clip_rect();
+                clip_rect.max.y = clip_rect.max.y.min(rect.top() + visible_height);
+                child_ui.set_clip_rect(clip_rect);
+
+                render_children(&mut child_ui, node_rects);
+                let measured_height = child_ui.min_rect().height();
+                ui.ctx().data_mut(|d| d.insert_temp(height_id, measured_height));
+
+                if self.style.indent_guides != IndentGuides::None {
+                    self.draw_indent_guides(&child_ui, node, depth, row_rect, &node_rects[first_descendant..], actions, response);
+                }
+                ui.allocate_space(egui::vec2(0.0, visible_height));
+            } else {
+                render_children(ui, node_rects);
+
+                if self.style.indent_guides != IndentGuides::None {
+                    self.draw_indent_guides(ui, node, depth, row_rect, &node_rects[first_descendant..], actions, response);
+                }
             }
         }
     }
 
-    /// Helper function to check if target_id is a descendant of source_id.
+    /// Returns how far a collection is expanded, from 0.0 (collapsed) to 1.0.
     ///
-    /// This is used to prevent circular dependencies in drag-drop operations.
-    fn is_descendant_of_impl<N>(all_nodes: &[N], target_id: &N::Id, source_id: &N::Id) -> bool
-    where
-        N: OutlinerNode,
-    {
-        // Find the source node
-        if let Some(source_node) = Self::find_node_by_id_impl(all_nodes, source_id) {
-            return Self::contains_descendant_impl(source_node, target_id);
+    /// Animates towards `is_expanded` over [`Style::expand_animation_time`];
+    /// without an animation time the result is exactly 0.0 or 1.0.
+    fn expand_openness<Id: std::hash::Hash>(&self, ctx: &egui::Context, id: &Id, is_expanded: bool) -> f32 {
+        if self.style.expand_animation_time > 0.0 {
+            ctx.animate_bool_with_time(self.id.with("expand").with(id), is_expanded, self.style.expand_animation_time)
+        } else if is_expanded {
+            1.0
+        } else {
+            0.0
         }
-        false
     }
 
-    /// Helper function to find a node by its ID.
-    fn find_node_by_id_impl<'a, N>(nodes: &'a [N], id: &N::Id) -> Option<&'a N>
-    where
-        N: OutlinerNode,
-    {
-        for node in nodes {
-            if node.id() == *id {
-                return Some(node);
-            }
-            if let Some(found) = Self::find_node_by_id_impl(node.children(), id) {
-                return Some(found);
+    /// Builds the shapes painted behind a whole row, bottom to top: the zebra
+    /// stripe, the label's [`LabelStyle::background`] tint and, in full-row
+    /// highlight mode, the selection or hover highlight.
+    fn row_background_shapes(
+        &self,
+        ui: &egui::Ui,
+        row_rect: egui::Rect,
+        row_index: usize,
+        label_tint: Option<(egui::Rect, egui::Color32)>,
+        is_selected: bool,
+        is_hovered: bool,
+    ) -> egui::Shape {
+        let radius = self.style.corner_radius;
+        let mut shapes = Vec::new();
+
+        if let Some(stripe_color) = self.style.stripe_color
+            && row_index % 2 == 1
+        {
+            shapes.push(egui::Shape::rect_filled(row_rect, self.style.stripe_corner_radius, stripe_color));
+        }
+
+        if let Some((label_rect, color)) = label_tint {
+            shapes.push(egui::Shape::rect_filled(label_rect, radius, color));
+        }
+
+        if self.style.full_row_highlight {
+            if is_selected {
+                let color = self.style.selection_color.unwrap_or_else(|| ui.visuals().selection.bg_fill);
+                shapes.push(egui::Shape::rect_filled(row_rect, radius, color));
+            } else if is_hovered {
+                let color = self.style.hover_color.unwrap_or_else(|| ui.visuals().widgets.hovered.bg_fill);
+                shapes.push(egui::Shape::rect_filled(row_rect, radius, color));
             }
         }
-        None
+
+        egui::Shape::Vec(shapes)
     }
 
-    /// Helper function to check if a node contains a descendant with the given ID.
-    fn contains_descendant_impl<N>(node: &N, target_id: &N::Id) -> bool
-    where
-        N: OutlinerNode,
-    {
-        for child in node.children() {
-            if child.id() == *target_id {
+    /// Draws the indentation guide of an expanded collection after its
+    /// descendants have been rendered.
+    ///
+    /// `descendant_rects` are the rows rendered below the collection, in order.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_indent_guides<N, A>(
+        &self,
+        ui: &egui::Ui,
+        node: &N,
+        depth: usize,
+        row_rect: egui::Rect,
+        descendant_rects: &[(N::Id, egui::Rect)],
+        actions: &A,
+        response: &OutlinerResponse<N::Id>,
+    ) where
+        N: OutlinerNode,
+        A: OutlinerActions<N>,
+    {
+        let child_ids: std::collections::HashSet<N::Id> = node.children().iter().map(|child| child.id()).collect();
+        let child_rects: Vec<egui::Rect> = descendant_rects
+            .iter()
+            .filter(|(id, _)| child_ids.contains(id))
+            .map(|(_, rect)| *rect)
+            .collect();
+        let Some(last_rect) = descendant_rects.last().map(|(_, rect)| *rect) else {
+            return;
+        };
+
+        // Highlight the guide when one of its direct children is hovered or selected
+        let is_active = child_ids
+            .iter()
+            .any(|id| response.hovered.as_ref() == Some(id) || actions.is_selected(id));
+        let color = if is_active {
+            self.style.indent_guide_active_color
+                .unwrap_or_else(|| ui.visuals().selection.stroke.color)
+        } else {
+            self.style.indent_guide_color
+                .unwrap_or_else(|| ui.visuals().widgets.noninteractive.bg_stroke.color)
+        };
+        let stroke = egui::Stroke::new(self.style.indent_guide_width, color);
+
+        for segment in self.indent_guide_segments(depth, row_rect, &child_rects, last_rect) {
+            ui.painter().line_segment(segment, stroke);
+        }
+    }
+
+    /// Computes the line segments of a collection's indentation guide.
+    ///
+    /// The guide runs at the center of the collection's expand icon. With
+    /// [`IndentGuides::Lines`] it spans all descendant rows; with
+    /// [`IndentGuides::Connectors`] it ends at the last child and each child
+    /// gets a horizontal stub.
+    fn indent_guide_segments(
+        &self,
+        depth: usize,
+        row_rect: egui::Rect,
+        child_rects: &[egui::Rect],
+        last_rect: egui::Rect,
+    ) -> Vec<[egui::Pos2; 2]> {
+        let x = row_rect.left() + depth as f32 * self.style.indent + self.style.expand_icon_size / 2.0;
+
+        match self.style.indent_guides {
+            IndentGuides::None => Vec::new(),
+            IndentGuides::Lines => {
+                let top = child_rects.first().map_or(row_rect.bottom(), |rect| rect.top());
+                vec![[egui::pos2(x, top), egui::pos2(x, last_rect.bottom())]]
+            }
+            IndentGuides::Connectors => {
+                let Some(last_child) = child_rects.last() else {
+                    return Vec::new();
+                };
+                let stub_end = row_rect.left() + (depth + 1) as f32 * self.style.indent - 2.0;
+
+                let mut segments = vec![[
+                    egui::pos2(x, row_rect.bottom()),
+                    egui::pos2(x, last_child.center().y),
+                ]];
+                segments.extend(child_rects.iter().map(|rect| {
+                    [egui::pos2(x, rect.center().y), egui::pos2(stub_end, rect.center().y)]
+                }));
+                segments
+            }
+        }
+    }
+
+    /// Helper function to check if target_id is a descendant of source_id.
+    ///
+    /// This is used to prevent circular dependencies in drag-drop operations.
+    fn is_descendant_of_impl<N>(all_nodes: &[N], target_id: &N::Id, source_id: &N::Id) -> bool
+    where
+        N: OutlinerNode,
+    {
+        // Find the source node
+        if let Some(source_node) = Self::find_node_by_id_impl(all_nodes, source_id) {
+            return Self::contains_descendant_impl(source_node, target_id);
+        }
+        false
+    }
+
+    /// Returns the pointer position and the dropped files (empty while they are
+    /// still hovering) while files are dragged in from the operating system.
+    fn file_drag(ui: &egui::Ui) -> Option<(egui::Pos2, Vec<egui::DroppedFile>)> {
+        let (hovering, dropped) = ui.input(|i| (!i.raw.hovered_files.is_empty(), i.raw.dropped_files.clone()));
+        if !hovering && dropped.is_empty() {
+            return None;
+        }
+        ui.ctx().pointer_latest_pos().map(|pointer| (pointer, dropped))
+    }
+
+    /// Returns whether the current press has moved far enough, and lasted long
+    /// enough, to start a drag (see [`with_drag_threshold`](Self::with_drag_threshold)).
+    fn drag_threshold_reached(&self, ui: &egui::Ui) -> bool {
+        ui.input(|i| {
+            let distance = i
+                .pointer
+                .press_origin()
+                .zip(i.pointer.interact_pos())
+                .map_or(0.0, |(origin, pos)| origin.distance(pos));
+            let held = i.pointer.press_start_time().map_or(0.0, |start| i.time - start);
+            distance >= self.drag_threshold && held >= self.drag_delay as f64
+        })
+    }
+
+    /// Renders the grip that starts drags in [`DragMode::Handle`].
+    fn render_drag_handle(&self, ui: &mut egui::Ui) -> egui::Response {
+        let (rect, response) = ui.allocate_exact_size(
+            egui::vec2(self.style.expand_icon_size, self.style.row_height),
+            egui::Sense::drag(),
+        );
+        let response = response.on_hover_cursor(egui::CursorIcon::Grab);
+
+        if ui.is_rect_visible(rect) {
+            let color = if response.hovered() || response.dragged() {
+                self.strong_text_color(ui)
+            } else {
+                self.style.drag_handle_color.unwrap_or(ui.visuals().weak_text_color())
+            };
+            ui.painter().text(
+                rect.center(),
+                egui::Align2::CENTER_CENTER,
+                "⠿",
+                egui::FontId::proportional(self.style.expand_icon_size),
+                color,
+            );
+        }
+        ui.add_space(self.style.icon_spacing);
+
+        response
+    }
+
+    /// Cancels the ongoing drag, returning the [`OutlinerEvent::DragCancelled`]
+    /// to report.
+    fn cancel_drag<Id>(state: &mut OutlinerState<Id>) -> OutlinerEvent<Id>
+    where
+        Id: std::hash::Hash + Eq + Clone + Send + Sync,
+    {
+        Self::abandon_drag(state);
+        OutlinerEvent::DragCancelled
+    }
+
+    /// Ends the ongoing drag without dropping into the tree.
+    fn abandon_drag<Id>(state: &mut OutlinerState<Id>)
+    where
+        Id: std::hash::Hash + Eq + Clone + Send + Sync,
+    {
+        state.drag_drop_mut().cancel_drag();
+        state.clear_dragging_nodes();
+    }
+
+    /// Returns the ancestors a drop after the given node can outdent to, nearest
+    /// first.
+    ///
+    /// This is the parent if the node is its last displayed child, then the
+    /// grandparent if the parent is a last child too, and so on.
+    fn outdent_targets<'a, N, A>(&self, nodes: &'a [N], id: &N::Id, actions: &A) -> Vec<&'a N>
+    where
+        N: OutlinerNode,
+        A: OutlinerActions<N>,
+    {
+        let mut path = Vec::new();
+        if !Self::ancestor_path(nodes, id, &mut path) {
+            return Vec::new();
+        }
+
+        let mut targets = Vec::new();
+        let mut child_id = id.clone();
+        for ancestor in path.into_iter().rev() {
+            let last_child = self.sort_mode.sorted(ancestor.children(), actions).last().map(|child| child.id());
+            if last_child.as_ref() != Some(&child_id) {
+                break;
+            }
+            child_id = ancestor.id();
+            targets.push(ancestor);
+        }
+        targets
+    }
+
+    /// Collects the ancestors of the node with the given ID, root first.
+    ///
+    /// Returns `true` if the node was found among `nodes` or their descendants.
+    fn ancestor_path<'a, N>(nodes: &'a [N], id: &N::Id, path: &mut Vec<&'a N>) -> bool
+    where
+        N: OutlinerNode,
+    {
+        for node in nodes {
+            if node.id() == *id {
+                return true;
+            }
+            path.push(node);
+            if Self::ancestor_path(node.children(), id, path) {
+                return true;
+            }
+            path.pop();
+        }
+        false
+    }
+
+    /// Expands every ancestor of the node with the given ID.
+    ///
+    /// Returns `true` if the node was found among `nodes` or their descendants.
+    fn expand_ancestors<N>(nodes: &[N], id: &N::Id, state: &mut OutlinerState<N::Id>) -> bool
+    where
+        N: OutlinerNode,
+    {
+        for node in nodes {
+            if node.id() == *id {
+                return true;
+            }
+            if Self::expand_ancestors(node.children(), id, state) {
+                state.set_expanded(&node.id(), true);
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Helper function to find a node by its ID.
+    fn find_node_by_id_impl<'a, N>(nodes: &'a [N], id: &N::Id) -> Option<&'a N>
+    where
+        N: OutlinerNode,
+    {
+        for node in nodes {
+            if node.id() == *id {
+                return Some(node);
+            }
+            if let Some(found) = Self::find_node_by_id_impl(node.children(), id) {
+                return Some(found);
+            }
+        }
+        None
+    }
+
+    /// Helper function to check if a node contains a descendant with the given ID.
+    fn contains_descendant_impl<N>(node: &N, target_id: &N::Id) -> bool
+    where
+        N: OutlinerNode,
+    {
+        for child in node.children() {
+            if child.id() == *target_id {
                 return true;
             }
             if Self::contains_descendant_impl(child, target_id) {
@@ -572,12 +1959,18 @@ impl Outliner {
 
     /// Renders the expand/collapse arrow icon.
     ///
+    /// `openness` is the animated expansion from 0.0 to 1.0. When expand
+    /// animations are enabled, rotating icon styles turn the collapsed glyph by
+    /// up to 90°; other styles switch glyphs halfway.
+    ///
     /// Returns the response from the arrow button/label.
-    fn render_expand_icon(&self, ui: &mut egui::Ui, is_expanded: bool) -> egui::Response {
-        let icon_text = if is_expanded {
-            self.style.expand_icon_style.expanded_str()
+    fn render_expand_icon(&self, ui: &mut egui::Ui, openness: f32) -> egui::Response {
+        let icon_style = &self.style.expand_icon_style;
+        let rotate = self.style.expand_animation_time > 0.0 && icon_style.rotates();
+        let icon_text = if rotate || openness < 0.5 {
+            icon_style.collapsed_str()
         } else {
-            self.style.expand_icon_style.collapsed_str()
+            icon_style.expanded_str()
         };
 
         let (rect, response) = ui.allocate_exact_size(
@@ -587,15 +1980,27 @@ impl Outliner {
 
         if ui.is_rect_visible(rect) {
             let visuals = ui.style().interact(&response);
-            let text_color = visuals.text_color();
+            let text_color = self.style.text_color.unwrap_or(visuals.text_color());
 
-            ui.painter().text(
-                rect.center(),
-                egui::Align2::CENTER_CENTER,
-                icon_text,
-                egui::FontId::proportional(self.style.expand_icon_size),
-                text_color,
-            );
+            if rotate {
+                // Rotate the glyph around its center
+                let galley = ui.painter().layout_no_wrap(
+                    icon_text.to_string(),
+                    egui::FontId::proportional(self.style.expand_icon_size),
+                    text_color,
+                );
+                let angle = openness * std::f32::consts::FRAC_PI_2;
+                let pos = rect.center() - egui::emath::Rot2::from_angle(angle) * (galley.size() / 2.0);
+                ui.painter().add(egui::epaint::TextShape::new(pos, galley, text_color).with_angle(angle));
+            } else {
+                ui.painter().text(
+                    rect.center(),
+                    egui::Align2::CENTER_CENTER,
+                    icon_text,
+                    egui::FontId::proportional(self.style.expand_icon_size),
+                    text_color,
+                );
+            }
         }
 
         response
@@ -621,26 +2026,79 @@ impl Outliner {
         A: OutlinerActions<N>,
     {
         if is_editing {
+            let node_id = node.id();
+            let edit_id = self.id.with("rename");
+            let just_started = state.take_editing_started();
+
             // Render text edit for renaming
-            let text_edit_response = ui.text_edit_singleline(state.editing_text_mut());
-
-            // Check for Enter key to confirm
-            if ui.input(|i| i.key_pressed(egui::Key::Enter)) {
-                let text = state.editing_text().to_string();
-                actions.on_rename(&node.id(), text.clone());
-                state.stop_editing();
-                response.renamed = Some((node.id(), text));
-                response.changed = true;
+            let mut output = egui::TextEdit::singleline(state.editing_text_mut())
+                .id(edit_id)
+                .show(ui);
+            let text_edit_response = output.response.clone();
+
+            // Focus the text edit (and select the old name) when editing starts
+            if just_started {
+                text_edit_response.request_focus();
+                if self.rename_options.select_all {
+                    let len = state.editing_text().chars().count();
+                    output.state.cursor.set_char_range(Some(egui::text::CCursorRange::two(
+                        egui::text::CCursor::new(0),
+                        egui::text::CCursor::new(len),
+                    )));
+                    output.state.store(ui.ctx(), edit_id);
+                }
             }
 
-            // Check for Escape key to cancel
-            if ui.input(|i| i.key_pressed(egui::Key::Escape)) {
-                state.stop_editing();
-                response.changed = true;
+            // Validate the normalized name on every frame for live feedback
+            let new_name = self.rename_options.normalize(state.editing_text());
+            let validation = self
+                .rename_options
+                .check(&new_name)
+                .and_then(|()| actions.validate_rename(&node_id, &new_name));
+
+            if let Err(message) = &validation {
+                let error_color = self.style.error_color.unwrap_or(ui.visuals().error_fg_color);
+                let rect = text_edit_response.rect;
+                let radius = self.style.corner_radius;
+                ui.painter().rect_filled(rect, radius, error_color.gamma_multiply(self.style.error_fill_opacity));
+                ui.painter().rect_stroke(
+                    rect,
+                    radius,
+                    egui::Stroke::new(self.style.error_stroke_width, error_color),
+                    egui::epaint::StrokeKind::Outside,
+                );
+
+                egui::Area::new(edit_id.with("error"))
+                    .order(egui::Order::Tooltip)
+                    .interactable(false)
+                    .fixed_pos(rect.left_bottom() + egui::vec2(0.0, 2.0))
+                    .show(ui.ctx(), |ui| {
+                        egui::Frame::popup(ui.style()).show(ui, |ui| {
+                            ui.colored_label(error_color, message);
+                        });
+                    });
             }
 
-            // Auto-focus the text edit
-            text_edit_response.request_focus();
+            // The text edit gives up focus on Enter, Escape or a click elsewhere, so
+            // keys only count when they made this field lose focus; keys pressed
+            // in other widgets (or other outliners) are ignored
+            if text_edit_response.lost_focus() {
+                let (enter, escape) =
+                    ui.input(|i| (i.key_pressed(egui::Key::Enter), i.key_pressed(egui::Key::Escape)));
+
+                if escape {
+                    state.stop_editing();
+                    response.push_event(OutlinerEvent::RenameCancelled(node_id));
+                } else if enter && validation.is_err() {
+                    // An invalid name keeps the editor open
+                    text_edit_response.request_focus();
+                } else if validation.is_ok() && (enter || self.rename_options.commit_on_blur) {
+                    self.commit_rename(node_id, new_name, state, actions, response);
+                } else {
+                    state.stop_editing();
+                    response.push_event(OutlinerEvent::RenameCancelled(node_id));
+                }
+            }
 
             text_edit_response
         } else {
@@ -648,50 +2106,225 @@ impl Outliner {
             let label_text = node.name();
             
             // Create a custom selectable label with our styling
-            // Include drag sensing so we can detect drag operations on the label
+            // Include drag sensing (unless a grip handle or nothing starts drags)
             // Reserve space for action icons to prevent layout shifts
             let available_width = ui.available_width();
             let label_width = (available_width - icons_width - 10.0).max(50.0);
+            let sense = if self.drag_mode == DragMode::Label {
+                egui::Sense::click_and_drag()
+            } else {
+                egui::Sense::click()
+            };
             
             let (rect, label_response) = ui.allocate_exact_size(
                 egui::vec2(label_width, self.style.row_height),
-                egui::Sense::click_and_drag(),
+                sense,
             );
+            let mut decoration_tooltip = None;
 
             if ui.is_rect_visible(rect) {
                 let visuals = ui.style().interact(&label_response);
-                
-                // Draw background if selected or hovered
-                if is_selected {
+                // The node's own tint is painted behind the row by render_node,
+                // below the selection and hover highlights
+                let label_style = node.label_style();
+
+                // Draw background if selected or hovered (unless the whole row is highlighted)
+                if self.style.full_row_highlight {
+                    // Painted behind the row by render_node
+                } else if is_selected {
                     let bg_color = self.style.selection_color
                         .unwrap_or_else(|| ui.visuals().selection.bg_fill);
-                    ui.painter().rect_filled(rect, 2.0, bg_color);
+                    ui.painter().rect_filled(rect, self.style.corner_radius, bg_color);
                 } else if label_response.hovered() {
                     let bg_color = self.style.hover_color
                         .unwrap_or_else(|| ui.visuals().widgets.hovered.bg_fill);
-                    ui.painter().rect_filled(rect, 2.0, bg_color);
+                    ui.painter().rect_filled(rect, self.style.corner_radius, bg_color);
                 }
 
                 // Draw text
                 let text_color = if is_selected {
-                    ui.visuals().selection.stroke.color
+                    self.style.selected_text_color
+                        .unwrap_or(ui.visuals().selection.stroke.color)
+                } else if label_style.strong {
+                    self.strong_text_color(ui)
                 } else {
-                    visuals.text_color()
+                    self.style.text_color.unwrap_or(visuals.text_color())
                 };
 
-                ui.painter().text(
-                    rect.left_center() + egui::vec2(4.0, 0.0),
-                    egui::Align2::LEFT_CENTER,
-                    label_text,
-                    egui::FontId::proportional(self.style.row_height * 0.8),
-                    text_color,
+                let format = self.label_text_format(&label_style, text_color);
+                let galley = ui.fonts(|fonts| {
+                    fonts.layout_job(egui::text::LayoutJob::single_section(label_text.to_string(), format))
+                });
+                let text_rect = egui::Align2::LEFT_CENTER
+                    .anchor_size(rect.left_center() + egui::vec2(4.0, 0.0), galley.size());
+                ui.painter().galley(text_rect.min, galley, text_color);
+
+                // Draw the node's decorations right after the text
+                let mut decorations = node.decorations();
+                if self.descendant_counts && node.is_collection() && !state.is_expanded(&node.id()) {
+                    let count = Self::count_descendants(node);
+                    if count > 0 {
+                        decorations.insert(0, Decoration::Count(count));
+                    }
+                }
+                decoration_tooltip = self.paint_decorations(
+                    ui,
+                    rect,
+                    text_rect.right() + self.style.icon_spacing,
+                    &decorations,
+                    label_response.hover_pos(),
                 );
             }
 
-            label_response
+            if let Some(tooltip) = decoration_tooltip {
+                label_response.on_hover_text(tooltip)
+            } else if node.has_tooltip() {
+                label_response.on_hover_ui(|ui| node.tooltip_ui(ui))
+            } else {
+                label_response
+            }
+        }
+    }
+
+    /// Builds the text format of a label from the node's [`LabelStyle`].
+    ///
+    /// `default_color` is the theme color for the label's current state; a custom
+    /// color in the label style takes precedence over it.
+    fn label_text_format(&self, label_style: &LabelStyle, default_color: egui::Color32) -> egui::TextFormat {
+        let color = label_style.color.unwrap_or(default_color);
+        let family = label_style.font_family.clone().unwrap_or(egui::FontFamily::Proportional);
+
+        egui::TextFormat {
+            font_id: egui::FontId::new(self.style.row_height * 0.8, family),
+            color,
+            italics: label_style.italics,
+            strikethrough: if label_style.strikethrough {
+                egui::Stroke::new(self.style.strikethrough_width, color)
+            } else {
+                egui::Stroke::NONE
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Paints decorations left to right starting at `x`, clipped to the label rect.
+    ///
+    /// Returns the tooltip of the decoration under `pointer`, if any.
+    fn paint_decorations(
+        &self,
+        ui: &egui::Ui,
+        rect: egui::Rect,
+        mut x: f32,
+        decorations: &[Decoration],
+        pointer: Option<egui::Pos2>,
+    ) -> Option<String> {
+        let painter = ui.painter().with_clip_rect(rect.intersect(ui.clip_rect()));
+        let colors = &self.style.decoration_colors;
+        let height = self.style.row_height * 0.7;
+        let center_y = rect.center().y;
+        let mut hovered_tooltip = None;
+
+        let paint_pill = |x: f32, text: String, fill: egui::Color32| {
+            let galley = painter.layout_no_wrap(
+                text,
+                egui::FontId::proportional(self.style.row_height * 0.55),
+                colors.text,
+            );
+            let width = (galley.size().x + height * 0.6).max(height);
+            let pill_rect = egui::Rect::from_min_size(
+                egui::pos2(x, center_y - height / 2.0),
+                egui::vec2(width, height),
+            );
+            painter.rect_filled(pill_rect, height / 2.0, fill);
+            painter.galley(pill_rect.center() - galley.size() / 2.0, galley, colors.text);
+            pill_rect
+        };
+
+        for decoration in decorations {
+            if x >= rect.right() {
+                break;
+            }
+
+            let decoration_rect = match decoration {
+                Decoration::Text { text, tone } => paint_pill(x, text.clone(), colors.color(*tone)),
+                Decoration::Count(count) => paint_pill(x, count.to_string(), colors.neutral),
+                Decoration::Dot(tone) => {
+                    let radius = self.style.row_height * 0.2;
+                    let dot_rect = egui::Rect::from_min_size(
+                        egui::pos2(x, center_y - radius),
+                        egui::vec2(radius * 2.0, radius * 2.0),
+                    );
+                    painter.circle_filled(dot_rect.center(), radius, colors.color(*tone));
+                    dot_rect
+                }
+                Decoration::Icon { icon, tone, .. } => painter.text(
+                    egui::pos2(x, center_y),
+                    egui::Align2::LEFT_CENTER,
+                    icon,
+                    egui::FontId::proportional(self.style.row_height * 0.7),
+                    colors.color(*tone),
+                ),
+            };
+
+            if pointer.is_some_and(|pos| decoration_rect.contains(pos)) {
+                hovered_tooltip = decoration.tooltip().map(str::to_string);
+            }
+            x = decoration_rect.right() + self.style.icon_spacing;
+        }
+
+        hovered_tooltip
+    }
+
+    /// Finishes an inline rename with the given (normalized, valid) name.
+    fn commit_rename<N, A>(
+        &self,
+        id: N::Id,
+        new_name: String,
+        state: &mut OutlinerState<N::Id>,
+        actions: &mut A,
+        response: &mut OutlinerResponse<N::Id>,
+    ) where
+        N: OutlinerNode,
+        A: OutlinerActions<N>,
+    {
+        actions.on_rename(&id, new_name.clone());
+        state.stop_editing();
+        response.push_event(OutlinerEvent::RenameCommitted { id, new_name });
+    }
+
+    /// Shows the hover tooltip for an action icon, if tooltips are enabled.
+    ///
+    /// The node's [`OutlinerNode::action_tooltip`] takes precedence over
+    /// `default_text`.
+    fn show_action_tooltip<N>(
+        &self,
+        icon_response: &egui::Response,
+        node: &N,
+        icon: &ActionIcon,
+        default_text: Option<&str>,
+    ) where
+        N: OutlinerNode,
+    {
+        if !self.action_tooltips {
+            return;
+        }
+
+        if let Some(text) = node.action_tooltip(icon).or_else(|| default_text.map(str::to_string)) {
+            icon_response.clone().on_hover_text(text);
         }
     }
 
+    /// Counts all descendants of a node recursively.
+    fn count_descendants<N>(node: &N) -> usize
+    where
+        N: OutlinerNode,
+    {
+        node.children()
+            .iter()
+            .map(|child| 1 + Self::count_descendants(child))
+            .sum()
+    }
+
     /// Collects all descendant node IDs recursively.
     ///
     /// This helper method traverses the tree starting from the given node
@@ -708,11 +2341,55 @@ impl Outliner {
         ids
     }
 
+    /// Returns the given IDs, each followed by the IDs of its node's descendants.
+    fn with_descendant_ids<N>(nodes: &[N], ids: &[N::Id]) -> Vec<N::Id>
+    where
+        N: OutlinerNode,
+    {
+        let mut all = Vec::new();
+        for id in ids {
+            all.push(id.clone());
+            if let Some(node) = Self::find_node_by_id_impl(nodes, id) {
+                all.extend(Self::collect_descendant_ids(node));
+            }
+        }
+        all
+    }
+
+    /// Returns the text color of strong labels: [`Style::strong_text_color`],
+    /// falling back to the themed text color before egui's.
+    fn strong_text_color(&self, ui: &egui::Ui) -> egui::Color32 {
+        self.style
+            .strong_text_color
+            .or(self.style.text_color)
+            .unwrap_or_else(|| ui.visuals().strong_text_color())
+    }
+
+    /// Returns the color of an action icon, dimmed by
+    /// [`Style::inactive_action_icon_opacity`] in the icon's off state.
+    fn action_icon_color(&self, ui: &egui::Ui, icon_response: &egui::Response, active: bool) -> egui::Color32 {
+        let color = self
+            .style
+            .action_icon_color
+            .unwrap_or_else(|| ui.style().interact(icon_response).text_color());
+        if active {
+            color
+        } else {
+            color.gamma_multiply(self.style.inactive_action_icon_opacity)
+        }
+    }
+
     /// Renders the action icons for a node.
     ///
     /// Icons are rendered right-to-left in the order they appear in the
     /// node's action_icons() list.
-    fn render_action_icons<N, A>(&self, ui: &mut egui::Ui, node: &N, actions: &mut A)
+    fn render_action_icons<N, A>(
+        &self,
+        ui: &mut egui::Ui,
+        node: &N,
+        actions: &mut A,
+        response: &mut OutlinerResponse<N::Id>,
+    )
     where
         N: OutlinerNode,
         A: OutlinerActions<N>,
@@ -732,12 +2409,7 @@ impl Outliner {
                     );
 
                     if ui.is_rect_visible(rect) {
-                        let visuals = ui.style().interact(&icon_response);
-                        let text_color = if is_visible {
-                            visuals.text_color()
-                        } else {
-                            visuals.text_color().gamma_multiply(0.5)
-                        };
+                        let text_color = self.action_icon_color(ui, &icon_response, is_visible);
 
                         ui.painter().text(
                             rect.center(),
@@ -748,6 +2420,13 @@ impl Outliner {
                         );
                     }
 
+                    self.show_action_tooltip(
+                        &icon_response,
+                        node,
+                        action_icon,
+                        Some(if is_visible { "Hide" } else { "Show" }),
+                    );
+
                     // Handle click to toggle visibility
                     if icon_response.clicked() {
                         // Determine the new visibility state (opposite of current)
@@ -768,6 +2447,11 @@ impl Outliner {
                                 }
                             }
                         }
+
+                        response.push_event(OutlinerEvent::VisibilityChanged {
+                            id: node_id.clone(),
+                            visible: new_visibility,
+                        });
                     }
                 }
                 ActionIcon::Lock => {
@@ -780,12 +2464,7 @@ impl Outliner {
                     );
 
                     if ui.is_rect_visible(rect) {
-                        let visuals = ui.style().interact(&icon_response);
-                        let text_color = if is_locked {
-                            visuals.text_color()
-                        } else {
-                            visuals.text_color().gamma_multiply(0.5)
-                        };
+                        let text_color = self.action_icon_color(ui, &icon_response, is_locked);
 
                         ui.painter().text(
                             rect.center(),
@@ -796,6 +2475,13 @@ impl Outliner {
                         );
                     }
 
+                    self.show_action_tooltip(
+                        &icon_response,
+                        node,
+                        action_icon,
+                        Some(if is_locked { "Unlock" } else { "Lock" }),
+                    );
+
                     // Handle click to toggle lock state
                     if icon_response.clicked() {
                         actions.on_lock_toggle(&node_id);
@@ -805,6 +2491,11 @@ impl Outliner {
                                 actions.on_lock_toggle(&child_id);
                             }
                         }
+
+                        response.push_event(OutlinerEvent::LockChanged {
+                            id: node_id.clone(),
+                            locked: !is_locked,
+                        });
                     }
                 }
                 ActionIcon::Selection => {
@@ -817,12 +2508,7 @@ impl Outliner {
                     );
 
                     if ui.is_rect_visible(rect) {
-                        let visuals = ui.style().interact(&icon_response);
-                        let text_color = if is_selected {
-                            visuals.text_color()
-                        } else {
-                            visuals.text_color().gamma_multiply(0.5)
-                        };
+                        let text_color = self.action_icon_color(ui, &icon_response, is_selected);
 
                         ui.painter().text(
                             rect.center(),
@@ -833,11 +2519,23 @@ impl Outliner {
                         );
                     }
 
+                    self.show_action_tooltip(
+                        &icon_response,
+                        node,
+                        action_icon,
+                        Some(if is_selected { "Deselect" } else { "Select" }),
+                    );
+
                     // Handle click to toggle selection
                     if icon_response.clicked() {
                         // Determine the new selection state based on current state
                         let current_state = actions.is_selected(&node_id);
                         let new_state = !current_state;
+                        let mut affected = vec![node_id.clone()];
+                        if is_collection {
+                            affected.extend(Self::collect_descendant_ids(node));
+                        }
+                        let before = Self::selected_ids(&affected, actions);
                         
                         // Apply the new state to the parent
                         actions.on_select(&node_id, new_state);
@@ -848,6 +2546,11 @@ impl Outliner {
                                 actions.on_select(&child_id, new_state);
                             }
                         }
+
+                        let after = Self::selected_ids(&affected, actions);
+                        if let Some(event) = OutlinerEvent::selection_change(&before, &after) {
+                            response.push_event(event);
+                        }
                     }
                 }
                 ActionIcon::Custom { icon, tooltip } => {
@@ -857,29 +2560,24 @@ impl Outliner {
                     );
 
                     if ui.is_rect_visible(rect) {
-                        let visuals = ui.style().interact(&icon_response);
-                        
                         ui.painter().text(
                             rect.center(),
                             egui::Align2::CENTER_CENTER,
                             icon.as_str(),
                             egui::FontId::proportional(self.style.action_icon_size * 0.8),
-                            visuals.text_color(),
+                            self.action_icon_color(ui, &icon_response, true),
                         );
                     }
 
+                    self.show_action_tooltip(&icon_response, node, action_icon, tooltip.as_deref());
+
                     // Handle click for custom action
-                    let clicked = icon_response.clicked();
-                    
-                    // Add tooltip if provided (consumes the response)
-                    let _icon_response = if let Some(tip) = tooltip {
-                        icon_response.on_hover_text(tip)
-                    } else {
-                        icon_response
-                    };
-
-                    if clicked {
+                    if icon_response.clicked() {
                         actions.on_custom_action(&node_id, icon.as_str());
+                        response.push_event(OutlinerEvent::CustomAction {
+                            id: node_id.clone(),
+                            icon: icon.clone(),
+                        });
                     }
                 }
             }
@@ -900,6 +2598,8 @@ mod tests {
         name: String,
         is_collection: bool,
         children: Vec<TestNode>,
+        decorations: Vec<Decoration>,
+        label_style: LabelStyle,
     }
 
     impl OutlinerNode for TestNode {
@@ -936,6 +2636,14 @@ mod tests {
         fn action_icons(&self) -> Vec<ActionIcon> {
             vec![ActionIcon::Visibility, ActionIcon::Lock, ActionIcon::Selection]
         }
+
+        fn decorations(&self) -> Vec<Decoration> {
+            self.decorations.clone()
+        }
+
+        fn label_style(&self) -> LabelStyle {
+            self.label_style.clone()
+        }
     }
 
     impl TestNode {
@@ -945,6 +2653,8 @@ mod tests {
                 name: name.to_string(),
                 is_collection,
                 children: Vec::new(),
+                decorations: Vec::new(),
+                label_style: LabelStyle::default(),
             }
         }
 
@@ -952,6 +2662,16 @@ mod tests {
             self.children = children;
             self
         }
+
+        fn with_decorations(mut self, decorations: Vec<Decoration>) -> Self {
+            self.decorations = decorations;
+            self
+        }
+
+        fn with_label_style(mut self, label_style: LabelStyle) -> Self {
+            self.label_style = label_style;
+            self
+        }
     }
 
     // Mock actions handler for testing
@@ -961,9 +2681,21 @@ mod tests {
         locked: HashSet<u64>,
         renamed: HashMap<u64, String>,
         moved: Vec<(u64, u64, DropPosition)>,
+        copied: Vec<(Vec<u64>, u64, DropPosition)>,
+        linked: Vec<(Vec<u64>, u64, DropPosition)>,
+        dropped_at_end: Vec<(Vec<u64>, DropOperation)>,
         custom_actions: Vec<(u64, String)>,
+        deleted: Vec<Vec<u64>>,
+        widget_width: f32,
+        widget_rows: Vec<(u64, RowInfo, egui::Rect)>,
+        widget_clicks: Vec<u64>,
+        custom_preview: bool,
+        previews: Vec<Vec<u64>>,
+        drop_rule: Option<DropRule>,
     }
 
+    type DropRule = fn(&[&TestNode], Option<&TestNode>, DropPosition) -> Result<(), String>;
+
     impl TestActions {
         fn new() -> Self {
             Self {
@@ -972,7 +2704,17 @@ mod tests {
                 locked: HashSet::new(),
                 renamed: HashMap::new(),
                 moved: Vec::new(),
+                copied: Vec::new(),
+                linked: Vec::new(),
+                dropped_at_end: Vec::new(),
                 custom_actions: Vec::new(),
+                deleted: Vec::new(),
+                widget_width: 0.0,
+                widget_rows: Vec::new(),
+                widget_clicks: Vec::new(),
+                custom_preview: false,
+                previews: Vec::new(),
+                drop_rule: None,
             }
         }
     }
@@ -982,10 +2724,34 @@ mod tests {
             self.renamed.insert(*id, new_name);
         }
 
+        fn validate_rename(&self, _id: &u64, new_name: &str) -> Result<(), String> {
+            if new_name == "Taken" {
+                Err("Name already in use".to_string())
+            } else {
+                Ok(())
+            }
+        }
+
         fn on_move(&mut self, id: &u64, target: &u64, position: DropPosition) {
             self.moved.push((*id, *target, position));
         }
 
+        fn on_copy(&mut self, ids: &[u64], target: &u64, position: DropPosition) {
+            self.copied.push((ids.to_vec(), *target, position));
+        }
+
+        fn on_link(&mut self, ids: &[u64], target: &u64, position: DropPosition) {
+            self.linked.push((ids.to_vec(), *target, position));
+        }
+
+        fn on_drop_at_end(&mut self, ids: &[u64], operation: DropOperation) {
+            self.dropped_at_end.push((ids.to_vec(), operation));
+        }
+
+        fn can_drop(&self, sources: &[&TestNode], target: Option<&TestNode>, position: DropPosition) -> Result<(), String> {
+            self.drop_rule.map_or(Ok(()), |rule| rule(sources, target, position))
+        }
+
         fn on_select(&mut self, id: &u64, selected: bool) {
             if selected {
                 self.selected.insert(*id);
@@ -1030,6 +2796,31 @@ mod tests {
         fn on_custom_action(&mut self, id: &u64, icon: &str) {
             self.custom_actions.push((*id, icon.to_string()));
         }
+
+        fn on_delete(&mut self, ids: &[u64]) {
+            self.deleted.push(ids.to_vec());
+        }
+
+        fn row_widget_width(&self, _node: &TestNode) -> f32 {
+            self.widget_width
+        }
+
+        fn has_drag_preview(&self) -> bool {
+            self.custom_preview
+        }
+
+        fn drag_preview_ui(&mut self, ui: &mut egui::Ui, nodes: &[&TestNode]) {
+            self.previews.push(nodes.iter().map(|node| node.id).collect());
+            ui.label("custom");
+        }
+
+        fn row_widget_ui(&mut self, ui: &mut egui::Ui, node: &TestNode, row: &RowInfo) {
+            self.widget_rows.push((node.id, *row, ui.max_rect()));
+            let button = egui::Button::new("W").min_size(egui::vec2(row.width, 0.0));
+            if ui.add(button).clicked() {
+                self.widget_clicks.push(node.id);
+            }
+        }
     }
 
     #[test]
@@ -1043,7 +2834,7 @@ mod tests {
         let state = OutlinerState::<u64>::default();
         let mut result = Vec::new();
         
-        Outliner::collect_visible_node_ids(&nodes, &state, &mut result);
+        Outliner::collect_visible_node_ids(&nodes, &state, SortMode::Manual, &TestActions::new(), &mut result);
         
         assert_eq!(result, vec![1, 2, 3]);
     }
@@ -1060,7 +2851,7 @@ mod tests {
         let state = OutlinerState::<u64>::default();
         let mut result = Vec::new();
         
-        Outliner::collect_visible_node_ids(&nodes, &state, &mut result);
+        Outliner::collect_visible_node_ids(&nodes, &state, SortMode::Manual, &TestActions::new(), &mut result);
         
         // Only parent should be visible when collapsed
         assert_eq!(result, vec![1]);
@@ -1079,7 +2870,7 @@ mod tests {
         state.set_expanded(&1, true);
         let mut result = Vec::new();
         
-        Outliner::collect_visible_node_ids(&nodes, &state, &mut result);
+        Outliner::collect_visible_node_ids(&nodes, &state, SortMode::Manual, &TestActions::new(), &mut result);
         
         // Parent and children should be visible when expanded
         assert_eq!(result, vec![1, 2, 3]);
@@ -1100,11 +2891,42 @@ mod tests {
         state.set_expanded(&2, true);
         let mut result = Vec::new();
         
-        Outliner::collect_visible_node_ids(&nodes, &state, &mut result);
+        Outliner::collect_visible_node_ids(&nodes, &state, SortMode::Manual, &TestActions::new(), &mut result);
         
         assert_eq!(result, vec![1, 2, 3]);
     }
 
+    #[test]
+    fn test_collect_visible_node_ids_sorted() {
+        let nodes = vec![
+            TestNode::new(1, "Zeta", false),
+            TestNode::new(2, "Group", true).with_children(vec![
+                TestNode::new(3, "Item 10", false),
+                TestNode::new(4, "Item 2", false),
+            ]),
+            TestNode::new(5, "alpha", false),
+        ];
+
+        let mut state = OutlinerState::<u64>::default();
+        state.set_expanded(&2, true);
+        let actions = TestActions::new();
+
+        let mut result = Vec::new();
+        Outliner::collect_visible_node_ids(&nodes, &state, SortMode::Name, &actions, &mut result);
+        assert_eq!(result, vec![5, 2, 4, 3, 1]);
+
+        let mut result = Vec::new();
+        Outliner::collect_visible_node_ids(&nodes, &state, SortMode::CollectionsFirst, &actions, &mut result);
+        assert_eq!(result, vec![2, 4, 3, 5, 1]);
+    }
+
+    #[test]
+    fn test_outliner_with_sort_mode() {
+        let outliner = Outliner::new("sorted").with_sort_mode(SortMode::Name);
+        assert_eq!(outliner.sort_mode, SortMode::Name);
+        assert!(Outliner::new("manual").sort_mode.is_manual());
+    }
+
     #[test]
     fn test_find_node_by_id_root_level() {
         let nodes = vec![
@@ -1216,6 +3038,224 @@ mod tests {
         assert!(ids.is_empty());
     }
 
+    #[test]
+    fn test_count_descendants() {
+        let node = TestNode::new(1, "Parent", true).with_children(vec![
+            TestNode::new(2, "Child1", false),
+            TestNode::new(3, "Child2", true).with_children(vec![
+                TestNode::new(4, "GrandChild", false),
+            ]),
+        ]);
+        assert_eq!(Outliner::count_descendants(&node), 3);
+        assert_eq!(Outliner::count_descendants(&TestNode::new(5, "Leaf", false)), 0);
+    }
+
+    #[test]
+    fn test_decoration_tooltip_hit_test() {
+        use crate::decoration::DecorationTone;
+
+        let ctx = egui::Context::default();
+        let decorations = vec![
+            Decoration::dot(DecorationTone::Accent),
+            Decoration::icon("!", DecorationTone::Warning).with_tooltip("Broken"),
+        ];
+        let outliner = Outliner::new("decorations");
+        let rect = egui::Rect::from_min_size(egui::pos2(0.0, 0.0), egui::vec2(300.0, 20.0));
+
+        let mut tooltips = Vec::new();
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                // The dot is 8px wide, followed by 4px spacing and the icon
+                for x in [4.0, 14.0, 250.0] {
+                    let pointer = Some(egui::pos2(x, 10.0));
+                    tooltips.push(outliner.paint_decorations(ui, rect, 0.0, &decorations, pointer));
+                }
+            });
+        });
+        assert_eq!(tooltips, vec![None, Some("Broken".to_string()), None]);
+    }
+
+    #[test]
+    fn test_outliner_with_decorations() {
+        use crate::decoration::DecorationTone;
+
+        let ctx = egui::Context::default();
+        let nodes = vec![
+            TestNode::new(1, "Group", true)
+                .with_children(vec![TestNode::new(2, "Cube", false)])
+                .with_decorations(vec![Decoration::text("LOD", DecorationTone::Neutral)]),
+            TestNode::new(3, "Light", false)
+                .with_decorations(vec![Decoration::icon("⚠", DecorationTone::Warning).with_tooltip("No shadows")]),
+        ];
+        let mut actions = TestActions::new();
+        let outliner = || Outliner::new("decorated").with_descendant_counts(true);
+        assert!(outliner().descendant_counts);
+        assert!(!Outliner::new("plain").descendant_counts);
+
+        run_outliner(&ctx, outliner, &nodes, &mut actions, vec![]);
+
+        // Decorations are part of the label, so clicking them selects the row
+        let response = click_at(&ctx, outliner, &nodes, &mut actions, egui::pos2(100.0, 18.0));
+        assert_eq!(response.selected(), Some(&1));
+    }
+
+    #[test]
+    fn test_label_text_format() {
+        let outliner = Outliner::new("labels");
+        let theme = egui::Color32::LIGHT_GRAY;
+
+        let plain = outliner.label_text_format(&LabelStyle::default(), theme);
+        assert_eq!(plain.color, theme);
+        assert_eq!(plain.font_id, egui::FontId::proportional(16.0));
+        assert!(!plain.italics);
+        assert_eq!(plain.strikethrough, egui::Stroke::NONE);
+
+        let styled = LabelStyle::default()
+            .with_color(egui::Color32::RED)
+            .with_italics(true)
+            .with_strikethrough(true)
+            .with_font_family(egui::FontFamily::Monospace);
+        let format = outliner.label_text_format(&styled, theme);
+        assert_eq!(format.color, egui::Color32::RED);
+        assert_eq!(format.font_id.family, egui::FontFamily::Monospace);
+        assert!(format.italics);
+        assert_eq!(format.strikethrough, egui::Stroke::new(1.0, egui::Color32::RED));
+
+        let style = Style { strikethrough_width: 2.0, ..Style::default() };
+        let format = Outliner::new("labels").with_style(style).label_text_format(&styled, theme);
+        assert_eq!(format.strikethrough, egui::Stroke::new(2.0, egui::Color32::RED));
+    }
+
+    #[test]
+    fn test_strong_text_color_follows_theme() {
+        let ctx = egui::Context::default();
+        let themed = Outliner::new("strong").with_style(Style::default().with_text_color(egui::Color32::KHAKI));
+        let strong = Outliner::new("strong").with_style(
+            Style::default().with_text_color(egui::Color32::KHAKI).with_strong_text_color(egui::Color32::GOLD),
+        );
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                assert_eq!(Outliner::new("strong").strong_text_color(ui), ui.visuals().strong_text_color());
+                assert_eq!(themed.strong_text_color(ui), egui::Color32::KHAKI);
+                assert_eq!(strong.strong_text_color(ui), egui::Color32::GOLD);
+            });
+        });
+    }
+
+    #[test]
+    fn test_action_icon_color_from_style() {
+        let ctx = egui::Context::default();
+        let style = Style { inactive_action_icon_opacity: 0.25, ..Style::default() }.with_action_icon_color(egui::Color32::GOLD);
+        let outliner = Outliner::new("icons").with_style(style);
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let response = ui.label("icon");
+                assert_eq!(outliner.action_icon_color(ui, &response, true), egui::Color32::GOLD);
+                assert_eq!(outliner.action_icon_color(ui, &response, false), egui::Color32::GOLD.gamma_multiply(0.25));
+            });
+        });
+    }
+
+    #[test]
+    fn test_outliner_with_label_styles() {
+        let ctx = egui::Context::default();
+        let nodes = vec![
+            TestNode::new(1, "Disabled", false)
+                .with_label_style(LabelStyle::default().with_color(egui::Color32::GRAY).with_strikethrough(true)),
+            TestNode::new(2, "Prefab", false).with_label_style(
+                LabelStyle::default()
+                    .with_strong(true)
+                    .with_italics(true)
+                    .with_background(egui::Color32::from_rgba_unmultiplied(0, 0, 255, 40)),
+            ),
+        ];
+        let mut actions = TestActions::new();
+        actions.on_select(&2, true);
+        run_outliner(&ctx, || Outliner::new("styled"), &nodes, &mut actions, vec![]);
+
+        let response = click_at(&ctx, || Outliner::new("styled"), &nodes, &mut actions, egui::pos2(100.0, 18.0));
+        assert_eq!(response.selected(), Some(&1));
+    }
+
+    #[test]
+    fn test_indent_guide_segments() {
+        let rect = |top: f32| egui::Rect::from_min_size(egui::pos2(0.0, top), egui::vec2(200.0, 20.0));
+        let parent = rect(0.0);
+        let children = [rect(20.0), rect(60.0)];
+        // The second child has a child of its own at y 80..100
+        let last = rect(80.0);
+
+        let outliner = |guides| Outliner::new("guides").with_style(Style::default().with_indent_guides(guides));
+
+        assert!(outliner(IndentGuides::None).indent_guide_segments(1, parent, &children, last).is_empty());
+
+        // Depth 1: x = 16 (indent) + 6 (half the expand icon)
+        assert_eq!(
+            outliner(IndentGuides::Lines).indent_guide_segments(1, parent, &children, last),
+            vec![[egui::pos2(22.0, 20.0), egui::pos2(22.0, 100.0)]]
+        );
+
+        // Connectors end at the last child and add a stub up to the child's indentation
+        assert_eq!(
+            outliner(IndentGuides::Connectors).indent_guide_segments(1, parent, &children, last),
+            vec![
+                [egui::pos2(22.0, 20.0), egui::pos2(22.0, 70.0)],
+                [egui::pos2(22.0, 30.0), egui::pos2(30.0, 30.0)],
+                [egui::pos2(22.0, 70.0), egui::pos2(30.0, 70.0)],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_outliner_with_indent_guides() {
+        let ctx = egui::Context::default();
+        let nodes = vec![TestNode::new(1, "Group", true).with_children(vec![
+            TestNode::new(2, "Cube", false),
+            TestNode::new(3, "Props", true).with_children(vec![TestNode::new(4, "Chair", false)]),
+        ])];
+        let mut state = OutlinerState::<u64>::default();
+        state.set_expanded(&1, true);
+        state.set_expanded(&3, true);
+        state.store(&ctx, egui::Id::new("guides"));
+
+        let mut actions = TestActions::new();
+        actions.on_select(&4, true);
+        for guides in [IndentGuides::Lines, IndentGuides::Connectors] {
+            let outliner = || Outliner::new("guides").with_style(Style::default().with_indent_guides(guides));
+            let response = run_outliner(&ctx, outliner, &nodes, &mut actions, vec![egui::Event::PointerMoved(egui::pos2(100.0, 40.0))]);
+            assert_eq!(response.hovered(), Some(&2));
+        }
+    }
+
+    #[test]
+    fn test_collect_selected_roots() {
+        let nodes = vec![
+            TestNode::new(1, "Parent", true).with_children(vec![
+                TestNode::new(2, "Child", false),
+                TestNode::new(3, "Child2", true).with_children(vec![
+                    TestNode::new(4, "GrandChild", false),
+                ]),
+            ]),
+            TestNode::new(5, "Other", false),
+        ];
+
+        let mut actions = TestActions::new();
+        actions.on_select(&1, true);
+        actions.on_select(&2, true);
+        actions.on_select(&4, true);
+        actions.on_select(&5, true);
+
+        let mut roots = Vec::new();
+        Outliner::collect_selected_roots(&nodes, &actions, &mut roots);
+        // Nodes 2 and 4 are covered by their selected ancestor
+        assert_eq!(roots, vec![1, 5]);
+
+        actions.on_select(&1, false);
+        let mut roots = Vec::new();
+        Outliner::collect_selected_roots(&nodes, &actions, &mut roots);
+        assert_eq!(roots, vec![2, 4, 5]);
+    }
+
     #[test]
     fn test_outliner_new() {
         let outliner = Outliner::new("test_outliner");
@@ -1237,4 +3277,1256 @@ mod tests {
         // Just verify it can be created with custom visuals
         assert_eq!(outliner.drag_drop_visuals.drop_line_thickness, 2.0);
     }
+
+    /// Runs one frame of an outliner over `nodes` with the given input events.
+    fn run_outliner(
+        ctx: &egui::Context,
+        outliner: impl Fn() -> Outliner,
+        nodes: &[TestNode],
+        actions: &mut TestActions,
+        events: Vec<egui::Event>,
+    ) -> OutlinerResponse<u64> {
+        let mut input = egui::RawInput {
+            screen_rect: Some(egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(400.0, 300.0))),
+            events,
+            ..Default::default()
+        };
+        // Keep the pointer over the outliner unless the test moves it itself
+        if !input.events.iter().any(|event| matches!(event, egui::Event::PointerMoved(_))) {
+            input.events.insert(0, egui::Event::PointerMoved(egui::pos2(200.0, 250.0)));
+        }
+        // Hold the modifiers of the last button or key event for the whole frame
+        if let Some(modifiers) = input.events.iter().rev().find_map(|event| match event {
+            egui::Event::PointerButton { modifiers, .. } | egui::Event::Key { modifiers, .. } => Some(*modifiers),
+            _ => None,
+        }) {
+            input.modifiers = modifiers;
+        }
+
+        let mut response = None;
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                response = Some(outliner().show(ui, nodes, actions));
+            });
+        });
+        response.unwrap()
+    }
+
+    #[test]
+    fn test_clipboard_commands_from_events() {
+        let ctx = egui::Context::default();
+        let nodes = vec![
+            TestNode::new(1, "Group", true).with_children(vec![TestNode::new(2, "Cube", false)]),
+            TestNode::new(3, "Light", false),
+        ];
+        let mut actions = TestActions::new();
+        actions.on_select(&1, true);
+        actions.on_select(&2, true);
+
+        // Let the first frame lay out the widget so the pointer is over it
+        run_outliner(&ctx, || Outliner::new("clip"), &nodes, &mut actions, vec![]);
+
+        let response = run_outliner(&ctx, || Outliner::new("clip"), &nodes, &mut actions, vec![egui::Event::Copy]);
+        assert_eq!(response.clipboard_command(), Some(&ClipboardCommand::Copy(vec![1])));
+        assert!(response.changed());
+
+        let response = run_outliner(
+            &ctx,
+            || Outliner::new("clip"),
+            &nodes,
+            &mut actions,
+            vec![egui::Event::Paste("text".to_string())],
+        );
+        assert_eq!(
+            response.clipboard_command(),
+            Some(&ClipboardCommand::Paste {
+                target: None,
+                position: DropPosition::After,
+                text: Some("text".to_string()),
+            })
+        );
+
+        let response = run_outliner(
+            &ctx,
+            || Outliner::new("clip").with_clipboard(false),
+            &nodes,
+            &mut actions,
+            vec![egui::Event::Cut],
+        );
+        assert!(response.clipboard_command().is_none());
+    }
+
+    #[test]
+    fn test_paste_after_cut_has_no_stale_target() {
+        let ctx = egui::Context::default();
+        let nodes = vec![TestNode::new(1, "Cube", false), TestNode::new(3, "Light", false)];
+        let mut actions = TestActions::new();
+        run_outliner(&ctx, || Outliner::new("cut"), &nodes, &mut actions, vec![]);
+        click_at(&ctx, || Outliner::new("cut"), &nodes, &mut actions, egui::pos2(100.0, 41.0));
+
+        let response = run_outliner(&ctx, || Outliner::new("cut"), &nodes, &mut actions, vec![egui::Event::Cut]);
+        assert_eq!(response.clipboard_command(), Some(&ClipboardCommand::Cut(vec![3])));
+
+        // The application removed the cut node but it is still selected
+        let remaining = vec![TestNode::new(1, "Cube", false)];
+        let paste = egui::Event::Paste(String::new());
+        let response = run_outliner(&ctx, || Outliner::new("cut"), &remaining, &mut actions, vec![paste]);
+        assert_eq!(
+            response.clipboard_command(),
+            Some(&ClipboardCommand::Paste {
+                target: None,
+                position: DropPosition::After,
+                text: Some(String::new()),
+            })
+        );
+    }
+
+    #[test]
+    fn test_duplicate_shortcut_reports_selection() {
+        let ctx = egui::Context::default();
+        let nodes = vec![TestNode::new(1, "Cube", false), TestNode::new(2, "Light", false)];
+        let mut actions = TestActions::new();
+        actions.on_select(&2, true);
+
+        let ctrl_d = egui::Event::Key {
+            key: egui::Key::D,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: egui::Modifiers::COMMAND,
+        };
+
+        run_outliner(&ctx, || Outliner::new("dup"), &nodes, &mut actions, vec![]);
+        let response = run_outliner(&ctx, || Outliner::new("dup"), &nodes, &mut actions, vec![ctrl_d.clone()]);
+        assert_eq!(response.duplicate_requested(), &[2]);
+
+        let response = run_outliner(
+            &ctx,
+            || Outliner::new("dup").with_duplicate_shortcut(None),
+            &nodes,
+            &mut actions,
+            vec![ctrl_d],
+        );
+        assert!(response.duplicate_requested().is_empty());
+    }
+
+    /// Presses and releases the primary button at `pos`, returning the response of
+    /// the release frame.
+    fn click_at(
+        ctx: &egui::Context,
+        outliner: impl Fn() -> Outliner,
+        nodes: &[TestNode],
+        actions: &mut TestActions,
+        pos: egui::Pos2,
+    ) -> OutlinerResponse<u64> {
+        let button = |pressed| egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed,
+            modifiers: egui::Modifiers::NONE,
+        };
+        run_outliner(ctx, &outliner, nodes, actions, vec![egui::Event::PointerMoved(pos), button(true)]);
+        run_outliner(ctx, &outliner, nodes, actions, vec![egui::Event::PointerMoved(pos), button(false)])
+    }
+
+    /// Presses the primary button at `from` and moves the pointer to `to` over
+    /// three frames without releasing, returning the responses of all frames.
+    fn drag_to(
+        ctx: &egui::Context,
+        outliner: impl Fn() -> Outliner,
+        nodes: &[TestNode],
+        actions: &mut TestActions,
+        from: egui::Pos2,
+        to: egui::Pos2,
+    ) -> Vec<OutlinerResponse<u64>> {
+        let press = egui::Event::PointerButton {
+            pos: from,
+            button: egui::PointerButton::Primary,
+            pressed: true,
+            modifiers: egui::Modifiers::NONE,
+        };
+        let mut responses = vec![run_outliner(ctx, &outliner, nodes, actions, vec![egui::Event::PointerMoved(from), press])];
+        for step in 1..=3 {
+            let pos = from.lerp(to, step as f32 / 3.0);
+            responses.push(run_outliner(ctx, &outliner, nodes, actions, vec![egui::Event::PointerMoved(pos)]));
+        }
+        responses
+    }
+
+    /// Releases the primary button at `pos`.
+    fn release_at(
+        ctx: &egui::Context,
+        outliner: impl Fn() -> Outliner,
+        nodes: &[TestNode],
+        actions: &mut TestActions,
+        pos: egui::Pos2,
+    ) -> OutlinerResponse<u64> {
+        let release = egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed: false,
+            modifiers: egui::Modifiers::NONE,
+        };
+        run_outliner(ctx, &outliner, nodes, actions, vec![egui::Event::PointerMoved(pos), release])
+    }
+
+    #[test]
+    fn test_drag_preview_callback() {
+        let ctx = egui::Context::default();
+        let nodes = vec![
+            TestNode::new(1, "Cube", false),
+            TestNode::new(2, "Light", false),
+            TestNode::new(3, "Camera", false),
+        ];
+        let mut actions = TestActions::new();
+        actions.custom_preview = true;
+        actions.on_select(&1, true);
+        actions.on_select(&2, true);
+        run_outliner(&ctx, || Outliner::new("preview"), &nodes, &mut actions, vec![]);
+
+        // Dragging a selected node drags the whole selection
+        let responses = drag_to(&ctx, || Outliner::new("preview"), &nodes, &mut actions, egui::pos2(100.0, 18.0), egui::pos2(100.0, 80.0));
+        assert!(responses.iter().any(|response| response.drag_started().is_some()));
+        assert_eq!(actions.previews.last(), Some(&vec![1, 2]));
+
+        // The preview disappears after the drop
+        release_at(&ctx, || Outliner::new("preview"), &nodes, &mut actions, egui::pos2(100.0, 80.0));
+        let count = actions.previews.len();
+        run_outliner(&ctx, || Outliner::new("preview"), &nodes, &mut actions, vec![]);
+        assert_eq!(actions.previews.len(), count);
+
+        // Previews can be switched off
+        let hidden = || {
+            let visuals = DragDropVisuals { show_preview: false, ..Default::default() };
+            Outliner::new("hidden").with_drag_drop_visuals(visuals)
+        };
+        drag_to(&ctx, hidden, &nodes, &mut actions, egui::pos2(100.0, 18.0), egui::pos2(100.0, 80.0));
+        assert_eq!(actions.previews.len(), count);
+    }
+
+    #[test]
+    fn test_drop_operation_from_modifiers() {
+        let ctx = egui::Context::default();
+        let nodes = vec![
+            TestNode::new(1, "Cube", false),
+            TestNode::new(2, "Light", false),
+            TestNode::new(3, "Camera", false),
+        ];
+        let mut actions = TestActions::new();
+        run_outliner(&ctx, || Outliner::new("ops"), &nodes, &mut actions, vec![]);
+
+        let drop_with = |actions: &mut TestActions, modifiers| {
+            let (from, to) = (egui::pos2(100.0, 18.0), egui::pos2(100.0, 66.0));
+            drag_to(&ctx, || Outliner::new("ops"), &nodes, actions, from, to);
+            let release = egui::Event::PointerButton {
+                pos: to,
+                button: egui::PointerButton::Primary,
+                pressed: false,
+                modifiers,
+            };
+            let response = run_outliner(&ctx, || Outliner::new("ops"), &nodes, actions, vec![egui::Event::PointerMoved(to), release]);
+            response.drop_event().map(|event| event.operation)
+        };
+
+        assert_eq!(drop_with(&mut actions, egui::Modifiers::NONE), Some(DropOperation::Move));
+        assert_eq!(drop_with(&mut actions, egui::Modifiers::COMMAND), Some(DropOperation::Copy));
+        assert_eq!(drop_with(&mut actions, egui::Modifiers::ALT), Some(DropOperation::Link));
+
+        // Each operation goes to its own hook
+        assert_eq!(actions.moved, vec![(1, 3, DropPosition::After)]);
+        assert_eq!(actions.copied, vec![(vec![1], 3, DropPosition::After)]);
+        assert_eq!(actions.linked, vec![(vec![1], 3, DropPosition::After)]);
+
+        // All dragged nodes are copied or linked in one call, in display order
+        actions.on_select(&2, true);
+        actions.on_select(&1, true);
+        assert_eq!(drop_with(&mut actions, egui::Modifiers::COMMAND), Some(DropOperation::Copy));
+        assert_eq!(drop_with(&mut actions, egui::Modifiers::ALT), Some(DropOperation::Link));
+        assert_eq!(&actions.copied[1..], &[(vec![1, 2], 3, DropPosition::After)]);
+        assert_eq!(&actions.linked[1..], &[(vec![1, 2], 3, DropPosition::After)]);
+    }
+
+    #[test]
+    fn test_multi_drag_rejects_secondary_dragged_nodes() {
+        let ctx = egui::Context::default();
+        let nodes = vec![
+            TestNode::new(1, "Group", true).with_children(vec![TestNode::new(2, "Cube", false)]),
+            TestNode::new(3, "Light", false),
+            TestNode::new(4, "Camera", true),
+        ];
+        let mut state = OutlinerState::<u64>::default();
+        state.set_expanded(&1, true);
+        state.store(&ctx, egui::Id::new("multi"));
+        let mut actions = TestActions::new();
+        actions.on_select(&1, true);
+        actions.on_select(&3, true);
+        run_outliner(&ctx, || Outliner::new("multi"), &nodes, &mut actions, vec![]);
+
+        // Dragging the Light also drags the selected Group, so neither the
+        // Group nor its child can be a target
+        let light = egui::pos2(100.0, 64.0);
+        for target in [egui::pos2(100.0, 18.0), egui::pos2(100.0, 41.0)] {
+            let responses = drag_to(&ctx, || Outliner::new("multi"), &nodes, &mut actions, light, target);
+            assert!(responses.iter().flat_map(|response| response.events()).all(|event| {
+                !matches!(event, OutlinerEvent::DragHovered { target: Some(1 | 2), .. })
+            }));
+            let state = OutlinerState::<u64>::load(&ctx, egui::Id::new("multi"));
+            assert_eq!(state.dragging_nodes(), &[1, 3]);
+            assert!(state.drag_drop().hover_target.is_none());
+            let response = release_at(&ctx, || Outliner::new("multi"), &nodes, &mut actions, target);
+            assert!(response.drop_event().is_none());
+        }
+        assert!(actions.moved.is_empty());
+
+        // Other collections still accept the whole selection
+        drag_to(&ctx, || Outliner::new("multi"), &nodes, &mut actions, light, egui::pos2(100.0, 87.0));
+        let response = release_at(&ctx, || Outliner::new("multi"), &nodes, &mut actions, egui::pos2(100.0, 87.0));
+        let drop = response.drop_event().expect("a collection outside the selection is a valid target");
+        assert_eq!((drop.target, drop.position), (Some(4), DropPosition::Inside));
+    }
+
+    #[test]
+    fn test_drop_in_empty_space_appends_at_root() {
+        let ctx = egui::Context::default();
+        let nodes = vec![
+            TestNode::new(1, "Cube", false),
+            TestNode::new(2, "Light", false),
+            TestNode::new(3, "Camera", false),
+        ];
+        let mut actions = TestActions::new();
+        run_outliner(&ctx, || Outliner::new("end"), &nodes, &mut actions, vec![]);
+
+        let (from, to) = (egui::pos2(100.0, 18.0), egui::pos2(100.0, 150.0));
+        let responses = drag_to(&ctx, || Outliner::new("end"), &nodes, &mut actions, from, to);
+        assert!(responses.iter().flat_map(|response| response.events()).any(|event| {
+            *event == OutlinerEvent::DragHovered { target: None, position: DropPosition::End }
+        }));
+
+        let response = release_at(&ctx, || Outliner::new("end"), &nodes, &mut actions, to);
+        let drop = response.drop_event().expect("dropping below the tree appends at root");
+        assert_eq!((drop.source, drop.target, drop.position), (1, None, DropPosition::End));
+        assert_eq!(actions.dropped_at_end, vec![(vec![1], DropOperation::Move)]);
+        assert!(actions.moved.is_empty());
+    }
+
+    #[test]
+    fn test_drop_last_root_at_end() {
+        let ctx = egui::Context::default();
+        let nodes = vec![TestNode::new(1, "Cube", false), TestNode::new(2, "Light", false)];
+        let mut actions = TestActions::new();
+        run_outliner(&ctx, || Outliner::new("last"), &nodes, &mut actions, vec![]);
+
+        // The last root can be dropped below itself without targeting itself
+        let (from, to) = (egui::pos2(100.0, 41.0), egui::pos2(100.0, 150.0));
+        drag_to(&ctx, || Outliner::new("last"), &nodes, &mut actions, from, to);
+        let state = OutlinerState::<u64>::load(&ctx, egui::Id::new("last"));
+        assert!(state.drag_drop().hover_target.is_none());
+        assert!(state.drag_drop().rejection().is_none());
+        assert_eq!(state.drag_drop().current_drop_position(), Some(DropPosition::End));
+
+        let response = release_at(&ctx, || Outliner::new("last"), &nodes, &mut actions, to);
+        let drop = response.drop_event().expect("the last root can be dropped at the end");
+        assert_eq!((drop.source, drop.target, drop.position), (2, None, DropPosition::End));
+        assert_eq!(actions.dropped_at_end, vec![(vec![2], DropOperation::Move)]);
+        assert!(!response.drag_cancelled());
+    }
+
+    #[test]
+    fn test_indent_aware_drop_outdents_last_child() {
+        let ctx = egui::Context::default();
+        let nodes = vec![
+            TestNode::new(1, "Group", true).with_children(vec![
+                TestNode::new(2, "Cube", false),
+                TestNode::new(3, "Light", false),
+            ]),
+            TestNode::new(4, "Camera", false),
+        ];
+        let mut state = OutlinerState::<u64>::default();
+        state.set_expanded(&1, true);
+        state.store(&ctx, egui::Id::new("indent"));
+        let outliner = || {
+            let visuals = DragDropVisuals { indent_aware: true, ..Default::default() };
+            Outliner::new("indent").with_drag_drop_visuals(visuals)
+        };
+        let mut actions = TestActions::new();
+        run_outliner(&ctx, outliner, &nodes, &mut actions, vec![]);
+
+        // Bottom edge of the last child, with the pointer inside its indentation level
+        let from = egui::pos2(100.0, 87.0);
+        drag_to(&ctx, outliner, &nodes, &mut actions, from, egui::pos2(100.0, 72.0));
+        let line = OutlinerState::<u64>::load(&ctx, egui::Id::new("indent")).drag_drop().drop_line;
+        assert_eq!(line, Some((3, 1)));
+        let response = release_at(&ctx, outliner, &nodes, &mut actions, egui::pos2(100.0, 72.0));
+        let drop = response.drop_event().unwrap();
+        assert_eq!((drop.target, drop.position), (Some(3), DropPosition::After));
+
+        // Moving the pointer left of the child's indentation outdents to the parent's level
+        drag_to(&ctx, outliner, &nodes, &mut actions, from, egui::pos2(12.0, 72.0));
+        let line = OutlinerState::<u64>::load(&ctx, egui::Id::new("indent")).drag_drop().drop_line;
+        assert_eq!(line, Some((3, 0)));
+        let response = release_at(&ctx, outliner, &nodes, &mut actions, egui::pos2(12.0, 72.0));
+        let drop = response.drop_event().unwrap();
+        assert_eq!((drop.target, drop.position), (Some(1), DropPosition::After));
+
+        // A child that isn't the last one can't outdent
+        drag_to(&ctx, outliner, &nodes, &mut actions, from, egui::pos2(12.0, 49.0));
+        let response = release_at(&ctx, outliner, &nodes, &mut actions, egui::pos2(12.0, 49.0));
+        let drop = response.drop_event().unwrap();
+        assert_eq!((drop.target, drop.position), (Some(2), DropPosition::After));
+    }
+
+    #[test]
+    fn test_events_for_clicks() {
+        let ctx = egui::Context::default();
+        let nodes = vec![
+            TestNode::new(1, "Group", true).with_children(vec![TestNode::new(2, "Cube", false)]),
+            TestNode::new(3, "Light", false),
+        ];
+        let mut actions = TestActions::new();
+        actions.on_select(&1, true);
+        run_outliner(&ctx, || Outliner::new("events"), &nodes, &mut actions, vec![]);
+
+        // Clicking the second row's label replaces the selection
+        let response = click_at(&ctx, || Outliner::new("events"), &nodes, &mut actions, egui::pos2(100.0, 40.0));
+        assert_eq!(
+            response.events(),
+            &[OutlinerEvent::SelectionChanged { added: vec![3], removed: vec![1] }]
+        );
+        assert_eq!(response.selected(), Some(&3));
+        assert!(response.changed());
+
+        // Clicking the first row's arrow expands the collection
+        let response = click_at(&ctx, || Outliner::new("events"), &nodes, &mut actions, egui::pos2(14.0, 18.0));
+        assert_eq!(response.events(), &[OutlinerEvent::Expanded(1)]);
+        assert_eq!(response.selected(), None);
+    }
+
+    #[test]
+    fn test_hovered_row() {
+        let ctx = egui::Context::default();
+        let nodes = vec![TestNode::new(1, "Cube", false), TestNode::new(2, "Light", false)];
+        let mut actions = TestActions::new();
+
+        let hover = |pos| vec![egui::Event::PointerMoved(pos)];
+        run_outliner(&ctx, || Outliner::new("hover"), &nodes, &mut actions, vec![]);
+        let response = run_outliner(&ctx, || Outliner::new("hover"), &nodes, &mut actions, hover(egui::pos2(100.0, 40.0)));
+        assert_eq!(response.hovered(), Some(&2));
+        assert!(!response.changed());
+
+        let response = run_outliner(&ctx, || Outliner::new("hover"), &nodes, &mut actions, hover(egui::pos2(100.0, 250.0)));
+        assert_eq!(response.hovered(), None);
+    }
+
+    #[test]
+    fn test_row_widget() {
+        let ctx = egui::Context::default();
+        let nodes = vec![
+            TestNode::new(1, "Group", true).with_children(vec![TestNode::new(2, "Cube", false)]),
+            TestNode::new(3, "Light", false),
+        ];
+        let mut actions = TestActions::new();
+
+        // Without a width the hook is never called
+        run_outliner(&ctx, || Outliner::new("widgets"), &nodes, &mut actions, vec![]);
+        assert!(actions.widget_rows.is_empty());
+
+        actions.widget_width = 60.0;
+        run_outliner(&ctx, || Outliner::new("widgets"), &nodes, &mut actions, vec![]);
+        let rows: Vec<_> = actions.widget_rows.iter().map(|(id, row, _)| (*id, row.depth)).collect();
+        assert_eq!(rows, vec![(1, 0), (3, 0)]);
+        let (_, row, rect) = actions.widget_rows[1];
+        assert_eq!(row.width, 60.0);
+        assert!(!row.is_selected && !row.is_editing);
+        assert!((rect.width() - 60.0).abs() < 1.0);
+
+        // Clicking the widget doesn't select or drag the row
+        actions.widget_rows.clear();
+        let response = click_at(&ctx, || Outliner::new("widgets"), &nodes, &mut actions, rect.center());
+        assert_eq!(actions.widget_clicks, vec![3]);
+        assert!(response.events().is_empty());
+        assert!(actions.selected.is_empty());
+    }
+
+    #[test]
+    fn test_row_background_shapes() {
+        let ctx = egui::Context::default();
+        let rect = egui::Rect::from_min_size(egui::pos2(0.0, 0.0), egui::vec2(200.0, 20.0));
+        let count = |style: Style, row_index, is_selected, is_hovered| {
+            let outliner = Outliner::new("rows").with_style(style);
+            let mut count = 0;
+            let _ = ctx.run(egui::RawInput::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    match outliner.row_background_shapes(ui, rect, row_index, None, is_selected, is_hovered) {
+                        egui::Shape::Vec(shapes) => count = shapes.len(),
+                        _ => panic!("expected a shape list"),
+                    }
+                });
+            });
+            count
+        };
+
+        // By default highlights are painted on the label only
+        assert_eq!(count(Style::default(), 1, true, true), 0);
+
+        let full_row = Style::default().with_full_row_highlight(true);
+        assert_eq!(count(full_row.clone(), 0, true, false), 1);
+        assert_eq!(count(full_row.clone(), 0, false, true), 1);
+        assert_eq!(count(full_row, 0, false, false), 0);
+
+        // Only odd rows are striped
+        let striped = Style::default().with_stripe_color(egui::Color32::from_gray(30));
+        assert_eq!(count(striped.clone(), 0, false, false), 0);
+        assert_eq!(count(striped, 1, false, false), 1);
+    }
+
+    #[test]
+    fn test_row_background_tint_below_highlight() {
+        let ctx = egui::Context::default();
+        let rect = egui::Rect::from_min_size(egui::pos2(0.0, 0.0), egui::vec2(200.0, 20.0));
+        let label_rect = rect.shrink(4.0);
+        let (stripe, tint, selection) = (egui::Color32::from_gray(30), egui::Color32::DARK_RED, egui::Color32::BLUE);
+        let style = Style::default()
+            .with_full_row_highlight(true)
+            .with_stripe_color(stripe)
+            .with_selection_color(selection);
+        let outliner = Outliner::new("tint").with_style(style);
+        let mut fills = Vec::new();
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let egui::Shape::Vec(shapes) = outliner.row_background_shapes(ui, rect, 1, Some((label_rect, tint)), true, false) else {
+                    panic!("expected a shape list");
+                };
+                fills = shapes
+                    .into_iter()
+                    .map(|shape| match shape {
+                        egui::Shape::Rect(rect) => rect.fill,
+                        _ => panic!("expected filled rects"),
+                    })
+                    .collect();
+            });
+        });
+
+        // Stripe first, then the label's tint, with the selection painted over it
+        assert_eq!(fills, vec![stripe, tint, selection]);
+    }
+
+    #[test]
+    fn test_full_row_highlight_selection() {
+        let ctx = egui::Context::default();
+        let nodes = vec![TestNode::new(1, "Cube", false), TestNode::new(2, "Light", false)];
+        let mut actions = TestActions::new();
+        let outliner = || {
+            Outliner::new("full_row").with_style(
+                Style::default()
+                    .with_full_row_highlight(true)
+                    .with_stripe_color(egui::Color32::from_gray(30))
+                    .with_focus_outline(egui::Stroke::new(1.0, egui::Color32::WHITE)),
+            )
+        };
+        run_outliner(&ctx, outliner, &nodes, &mut actions, vec![]);
+
+        let response = click_at(&ctx, outliner, &nodes, &mut actions, egui::pos2(100.0, 40.0));
+        assert_eq!(response.selected(), Some(&2));
+        let response = run_outliner(&ctx, outliner, &nodes, &mut actions, vec![]);
+        assert!(!response.changed());
+    }
+
+    #[test]
+    fn test_expand_openness_without_animation() {
+        let ctx = egui::Context::default();
+        let outliner = Outliner::new("instant");
+        assert_eq!(outliner.expand_openness(&ctx, &1u64, true), 1.0);
+        assert_eq!(outliner.expand_openness(&ctx, &1u64, false), 0.0);
+    }
+
+    #[test]
+    fn test_expand_animation_hides_clipped_rows() {
+        let ctx = egui::Context::default();
+        let nodes = vec![TestNode::new(1, "Group", true).with_children(vec![
+            TestNode::new(2, "Cube", false),
+            TestNode::new(3, "Chair", false),
+        ])];
+        let mut actions = TestActions::new();
+        let outliner = || Outliner::new("animated").with_style(Style::default().with_expand_animation_time(10.0));
+
+        // Let the animation see the collapsed state first
+        run_outliner(&ctx, outliner, &nodes, &mut actions, vec![]);
+
+        // Expanding starts the animation; the second row is still clipped away
+        let response = click_at(&ctx, outliner, &nodes, &mut actions, egui::pos2(14.0, 18.0));
+        assert_eq!(response.events(), &[OutlinerEvent::Expanded(1)]);
+
+        let hover = vec![egui::Event::PointerMoved(egui::pos2(100.0, 40.0))];
+        let response = run_outliner(&ctx, outliner, &nodes, &mut actions, hover);
+        let openness = outliner().expand_openness(&ctx, &1u64, true);
+        assert!(openness > 0.0 && openness < 1.0, "{openness}");
+        assert_eq!(response.hovered(), None);
+    }
+
+    #[test]
+    fn test_outliner_with_theme() {
+        let outliner = Outliner::new("themed").with_theme(Theme::touch());
+        assert_eq!(outliner.style, Style::touch());
+        assert_eq!(outliner.drag_drop_visuals, Theme::touch().drag_drop);
+    }
+
+    #[test]
+    fn test_outliner_with_action_tooltips() {
+        assert!(Outliner::new("tips").action_tooltips);
+        assert!(!Outliner::new("tips").with_action_tooltips(false).action_tooltips);
+    }
+
+    /// Stores outliner state with node 1 being renamed from "Cube".
+    fn start_rename(ctx: &egui::Context, outliner_id: &str) {
+        let mut state = OutlinerState::<u64>::default();
+        state.start_editing(1, "Cube".to_string());
+        state.store(ctx, egui::Id::new(outliner_id));
+    }
+
+    fn type_text(text: &str) -> egui::Event {
+        egui::Event::Text(text.to_string())
+    }
+
+    #[test]
+    fn test_rename_validation_blocks_commit() {
+        let ctx = egui::Context::default();
+        let nodes = vec![TestNode::new(1, "Cube", false)];
+        let mut actions = TestActions::new();
+        let outliner = || Outliner::new("rename");
+
+        start_rename(&ctx, "rename");
+        run_outliner(&ctx, outliner, &nodes, &mut actions, vec![]);
+
+        // The old name is selected, so typing replaces it
+        run_outliner(&ctx, outliner, &nodes, &mut actions, vec![type_text("Taken")]);
+        let response = run_outliner(&ctx, outliner, &nodes, &mut actions, vec![key_press(egui::Key::Enter)]);
+        assert!(response.renamed().is_none());
+        assert!(actions.renamed.is_empty());
+
+        // Whitespace-only names are empty after trimming
+        run_outliner(&ctx, outliner, &nodes, &mut actions, vec![key_press(egui::Key::Backspace); 5]);
+        run_outliner(&ctx, outliner, &nodes, &mut actions, vec![type_text("   ")]);
+        let response = run_outliner(&ctx, outliner, &nodes, &mut actions, vec![key_press(egui::Key::Enter)]);
+        assert!(response.renamed().is_none());
+
+        run_outliner(&ctx, outliner, &nodes, &mut actions, vec![type_text("Sphere ")]);
+        let response = run_outliner(&ctx, outliner, &nodes, &mut actions, vec![key_press(egui::Key::Enter)]);
+        assert_eq!(response.renamed(), Some((&1, "Sphere")));
+        assert_eq!(actions.renamed.get(&1).map(String::as_str), Some("Sphere"));
+    }
+
+    #[test]
+    fn test_begin_rename_and_independent_outliners() {
+        let ctx = egui::Context::default();
+        let nodes = vec![
+            TestNode::new(1, "Group", true).with_children(vec![TestNode::new(2, "Cube", false)]),
+        ];
+        let mut actions = TestActions::new();
+        let mut other_actions = TestActions::new();
+
+        // Two outliners side by side showing the same tree
+        let run = |ctx: &egui::Context, actions: &mut TestActions, other: &mut TestActions, events| {
+            let mut input = egui::RawInput {
+                screen_rect: Some(egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(400.0, 300.0))),
+                events,
+                ..Default::default()
+            };
+            input.events.insert(0, egui::Event::PointerMoved(egui::pos2(100.0, 250.0)));
+            let mut responses = None;
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    ui.columns(2, |columns| {
+                        let left = Outliner::new("left").show(&mut columns[0], &nodes, actions);
+                        let right = Outliner::new("right").show(&mut columns[1], &nodes, other);
+                        responses = Some((left, right));
+                    });
+                });
+            });
+            responses.unwrap()
+        };
+
+        Outliner::new("left").begin_rename(&ctx, 2u64);
+        let (left, right) = run(&ctx, &mut actions, &mut other_actions, vec![]);
+        assert_eq!(left.events(), &[OutlinerEvent::RenameStarted(2)]);
+        assert!(right.events().is_empty());
+
+        // The nested node was revealed and is being edited
+        let state = OutlinerState::<u64>::load(&ctx, egui::Id::new("left"));
+        assert!(state.is_expanded(&1));
+        assert!(state.is_editing(&2));
+
+        run(&ctx, &mut actions, &mut other_actions, vec![type_text("Sphere")]);
+        let (left, right) = run(&ctx, &mut actions, &mut other_actions, vec![key_press(egui::Key::Enter)]);
+        assert_eq!(left.renamed(), Some((&2, "Sphere")));
+        assert!(right.renamed().is_none());
+        assert!(other_actions.renamed.is_empty());
+    }
+
+    #[test]
+    fn test_enter_elsewhere_does_not_commit_rename() {
+        let ctx = egui::Context::default();
+        let nodes = vec![TestNode::new(1, "Cube", false)];
+        let mut actions = TestActions::new();
+        let mut other_text = String::new();
+
+        start_rename(&ctx, "focus");
+        let mut frame = |events: Vec<egui::Event>, focus_other: bool| {
+            let input = egui::RawInput {
+                screen_rect: Some(egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(400.0, 300.0))),
+                events,
+                ..Default::default()
+            };
+            let mut response = None;
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let other = ui.text_edit_singleline(&mut other_text);
+                    if focus_other {
+                        other.request_focus();
+                    }
+                    response = Some(
+                        Outliner::new("focus")
+                            .with_rename_options(RenameOptions::default().with_commit_on_blur(false))
+                            .show(ui, &nodes, &mut actions),
+                    );
+                });
+            });
+            response.unwrap()
+        };
+
+        frame(vec![], false);
+        // Another field takes focus, which ends the rename without committing
+        let mut events = frame(vec![], true).events;
+        events.extend(frame(vec![], false).events);
+        assert!(events.contains(&OutlinerEvent::RenameCancelled(1)));
+
+        // Enter typed into the other field is not a rename confirmation
+        let response = frame(vec![type_text("x"), key_press(egui::Key::Enter)], false);
+        assert!(response.renamed().is_none());
+        assert!(actions.renamed.is_empty());
+    }
+
+    #[test]
+    fn test_rename_blur_behavior() {
+        let ctx = egui::Context::default();
+        let nodes = vec![TestNode::new(1, "Cube", false)];
+        let mut actions = TestActions::new();
+        let click_away = |pressed| egui::Event::PointerButton {
+            pos: egui::pos2(200.0, 250.0),
+            button: egui::PointerButton::Primary,
+            pressed,
+            modifiers: egui::Modifiers::NONE,
+        };
+
+        for commit_on_blur in [true, false] {
+            let outliner = || {
+                Outliner::new("blur").with_rename_options(
+                    RenameOptions::default().with_commit_on_blur(commit_on_blur),
+                )
+            };
+            actions.renamed.clear();
+            start_rename(&ctx, "blur");
+            run_outliner(&ctx, outliner, &nodes, &mut actions, vec![]);
+            run_outliner(&ctx, outliner, &nodes, &mut actions, vec![type_text("Cone")]);
+            // Focus moves away as soon as the button is pressed
+            let response = run_outliner(&ctx, outliner, &nodes, &mut actions, vec![click_away(true)]);
+            run_outliner(&ctx, outliner, &nodes, &mut actions, vec![click_away(false)]);
+
+            if commit_on_blur {
+                assert_eq!(response.renamed(), Some((&1, "Cone")));
+                assert_eq!(actions.renamed.get(&1).map(String::as_str), Some("Cone"));
+            } else {
+                assert!(response.events().contains(&OutlinerEvent::RenameCancelled(1)));
+                assert!(actions.renamed.is_empty());
+            }
+        }
+    }
+
+    fn key_press(key: egui::Key) -> egui::Event {
+        egui::Event::Key {
+            key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: egui::Modifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn test_drag_threshold_turns_short_drags_into_clicks() {
+        let ctx = egui::Context::default();
+        let nodes = vec![
+            TestNode::new(1, "Cube", false),
+            TestNode::new(2, "Light", false),
+            TestNode::new(3, "Camera", false),
+        ];
+        let outliner = || Outliner::new("threshold").with_drag_threshold(40.0, 0.0);
+        let mut actions = TestActions::new();
+        run_outliner(&ctx, outliner, &nodes, &mut actions, vec![]);
+
+        // Moving less than the threshold doesn't drag, and releasing selects
+        let from = egui::pos2(100.0, 18.0);
+        let responses = drag_to(&ctx, outliner, &nodes, &mut actions, from, egui::pos2(100.0, 41.0));
+        assert!(responses.iter().all(|response| response.drag_started().is_none()));
+        let response = release_at(&ctx, outliner, &nodes, &mut actions, egui::pos2(100.0, 41.0));
+        assert_eq!(response.selected(), Some(&1));
+        assert!(response.drop_event().is_none());
+
+        // Moving further starts the drag
+        let responses = drag_to(&ctx, outliner, &nodes, &mut actions, from, egui::pos2(100.0, 66.0));
+        assert!(responses.iter().any(|response| response.drag_started() == Some(&1)));
+        release_at(&ctx, outliner, &nodes, &mut actions, egui::pos2(100.0, 66.0));
+        assert_eq!(actions.moved, vec![(1, 3, DropPosition::After)]);
+    }
+
+    #[test]
+    fn test_drag_modes() {
+        let ctx = egui::Context::default();
+        let nodes = vec![TestNode::new(1, "Cube", false), TestNode::new(2, "Light", false)];
+        let mut actions = TestActions::new();
+        let (label, target) = (egui::pos2(100.0, 18.0), egui::pos2(100.0, 45.0));
+
+        // Read-only views can't drag at all
+        let disabled = || Outliner::new("disabled").with_drag_mode(DragMode::Disabled);
+        run_outliner(&ctx, disabled, &nodes, &mut actions, vec![]);
+        let responses = drag_to(&ctx, disabled, &nodes, &mut actions, label, target);
+        assert!(responses.iter().all(|response| response.drag_started().is_none()));
+        release_at(&ctx, disabled, &nodes, &mut actions, target);
+
+        // In handle mode the label only selects, and the grip drags
+        actions.widget_width = 20.0;
+        let handle = || Outliner::new("handle").with_drag_mode(DragMode::Handle);
+        run_outliner(&ctx, handle, &nodes, &mut actions, vec![]);
+        let responses = drag_to(&ctx, handle, &nodes, &mut actions, label, target);
+        assert!(responses.iter().all(|response| response.drag_started().is_none()));
+        release_at(&ctx, handle, &nodes, &mut actions, target);
+
+        let style = Style::default();
+        let widget_rect = actions.widget_rows.iter().find(|(id, _, _)| *id == 1).unwrap().2;
+        let grip = egui::pos2(widget_rect.left() - style.icon_spacing - style.expand_icon_size / 2.0, 18.0);
+        let responses = drag_to(&ctx, handle, &nodes, &mut actions, grip, target);
+        assert!(responses.iter().any(|response| response.drag_started() == Some(&1)));
+        release_at(&ctx, handle, &nodes, &mut actions, target);
+        assert_eq!(actions.moved, vec![(1, 2, DropPosition::After)]);
+    }
+
+    #[test]
+    fn test_files_dropped_on_rows() {
+        let ctx = egui::Context::default();
+        let nodes = vec![TestNode::new(1, "Assets", true), TestNode::new(2, "Cube", false)];
+        let mut actions = TestActions::new();
+        let file = egui::DroppedFile {
+            path: Some("textures/wood.png".into()),
+            name: "wood.png".to_string(),
+            ..Default::default()
+        };
+
+        // Runs a frame with files hovering at `pos`, or dropped there
+        let mut run = |pos: egui::Pos2, dropped: Vec<egui::DroppedFile>| {
+            let input = egui::RawInput {
+                screen_rect: Some(egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(400.0, 300.0))),
+                events: vec![egui::Event::PointerMoved(pos)],
+                hovered_files: if dropped.is_empty() { vec![egui::HoveredFile::default()] } else { Vec::new() },
+                dropped_files: dropped,
+                ..Default::default()
+            };
+            let mut response = None;
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    response = Some(Outliner::new("files").show(ui, &nodes, &mut actions));
+                });
+            });
+            response.unwrap()
+        };
+
+        // Hovering files over a collection targets its inside
+        run(egui::pos2(100.0, 18.0), Vec::new());
+        let state = OutlinerState::<u64>::load(&ctx, egui::Id::new("files"));
+        assert!(state.drag_drop().is_hover_target(&1));
+        assert_eq!(state.drag_drop().current_drop_position(), Some(DropPosition::Inside));
+
+        let response = run(egui::pos2(100.0, 18.0), vec![file.clone()]);
+        assert_eq!(response.files_dropped(), Some((Some(&1), DropPosition::Inside, &[file.clone()][..])));
+        assert!(OutlinerState::<u64>::load(&ctx, egui::Id::new("files")).drag_drop().hover_target.is_none());
+
+        // Dropping below the tree appends at the root level
+        let response = run(egui::pos2(100.0, 200.0), vec![file.clone()]);
+        assert_eq!(response.files_dropped(), Some((None, DropPosition::End, &[file][..])));
+    }
+
+    #[test]
+    fn test_files_dropped_on_empty_tree() {
+        let ctx = egui::Context::default();
+        let nodes: Vec<TestNode> = Vec::new();
+        let mut actions = TestActions::new();
+        let file = egui::DroppedFile { name: "scene.gltf".to_string(), ..Default::default() };
+
+        let mut run = |dropped: Vec<egui::DroppedFile>| {
+            let input = egui::RawInput {
+                screen_rect: Some(egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(400.0, 300.0))),
+                events: vec![egui::Event::PointerMoved(egui::pos2(100.0, 18.0))],
+                hovered_files: if dropped.is_empty() { vec![egui::HoveredFile::default()] } else { Vec::new() },
+                dropped_files: dropped,
+                ..Default::default()
+            };
+            let mut response = None;
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    response = Some(Outliner::new("empty").show(ui, &nodes, &mut actions));
+                });
+            });
+            response.unwrap()
+        };
+
+        // With no rows at all, the whole outliner appends at the root level
+        run(Vec::new());
+        let state = OutlinerState::<u64>::load(&ctx, egui::Id::new("empty"));
+        assert_eq!(state.drag_drop().current_drop_position(), Some(DropPosition::End));
+        let response = run(vec![file.clone()]);
+        assert_eq!(response.files_dropped(), Some((None, DropPosition::End, &[file][..])));
+    }
+
+    #[test]
+    fn test_can_drop_rejects_hovered_target() {
+        let ctx = egui::Context::default();
+        let nodes = vec![
+            TestNode::new(1, "Group", true),
+            TestNode::new(2, "Cube", false),
+            TestNode::new(3, "Light", false),
+        ];
+        let mut actions = TestActions::new();
+        actions.drop_rule = Some(|sources, target, position| match target {
+            Some(target) if position == DropPosition::Inside && sources.iter().any(|node| node.name == "Light") => {
+                Err(format!("Lights can't go into {}", target.name))
+            }
+            _ => Ok(()),
+        });
+        let (group, cube, light) = (egui::pos2(100.0, 18.0), egui::pos2(100.0, 41.0), egui::pos2(100.0, 64.0));
+        run_outliner(&ctx, || Outliner::new("rules"), &nodes, &mut actions, vec![]);
+
+        // A rejected target is not hovered and can't be dropped on
+        let responses = drag_to(&ctx, || Outliner::new("rules"), &nodes, &mut actions, light, group);
+        assert!(responses.iter().flat_map(|response| response.events()).all(|event| {
+            !matches!(event, OutlinerEvent::DragHovered { target: Some(1), .. })
+        }));
+        let state = OutlinerState::<u64>::load(&ctx, egui::Id::new("rules"));
+        assert_eq!(state.drag_drop().rejection(), Some((Some(&1), DropPosition::Inside, "Lights can't go into Group")));
+        let response = release_at(&ctx, || Outliner::new("rules"), &nodes, &mut actions, group);
+        assert!(response.drop_event().is_none());
+        assert!(response.drag_cancelled());
+
+        // Other nodes may still go inside
+        drag_to(&ctx, || Outliner::new("rules"), &nodes, &mut actions, cube, group);
+        release_at(&ctx, || Outliner::new("rules"), &nodes, &mut actions, group);
+        assert_eq!(actions.moved, vec![(2, 1, DropPosition::Inside)]);
+
+        // Multi-drag checks every dragged node
+        actions.on_select(&2, true);
+        actions.on_select(&3, true);
+        drag_to(&ctx, || Outliner::new("rules"), &nodes, &mut actions, cube, group);
+        let response = release_at(&ctx, || Outliner::new("rules"), &nodes, &mut actions, group);
+        assert!(response.drop_event().is_none());
+        assert_eq!(actions.moved.len(), 1);
+    }
+
+    #[test]
+    fn test_escape_cancels_drag() {
+        let ctx = egui::Context::default();
+        let nodes = vec![
+            TestNode::new(1, "Group", true).with_children(vec![TestNode::new(2, "Cube", false)]),
+            TestNode::new(3, "Light", false),
+        ];
+        let outliner = || Outliner::new("cancel");
+        let mut actions = TestActions::new();
+        run_outliner(&ctx, outliner, &nodes, &mut actions, vec![]);
+        drag_to(&ctx, outliner, &nodes, &mut actions, egui::pos2(100.0, 41.0), egui::pos2(100.0, 18.0));
+
+        let pointer = egui::Event::PointerMoved(egui::pos2(100.0, 18.0));
+        let response = run_outliner(&ctx, outliner, &nodes, &mut actions, vec![pointer.clone(), key_press(egui::Key::Escape)]);
+        assert_eq!(response.events(), &[OutlinerEvent::DragCancelled]);
+        assert!(response.drag_cancelled());
+        let state = OutlinerState::<u64>::load(&ctx, egui::Id::new("cancel"));
+        assert!(!state.drag_drop().is_dragging());
+        assert!(state.dragging_nodes().is_empty());
+
+        // Releasing afterwards doesn't drop anything
+        let response = release_at(&ctx, outliner, &nodes, &mut actions, egui::pos2(100.0, 18.0));
+        assert!(response.drop_event().is_none());
+        assert!(actions.moved.is_empty());
+    }
+
+    #[test]
+    fn test_secondary_click_cancels_drag() {
+        let ctx = egui::Context::default();
+        let nodes = vec![TestNode::new(1, "Cube", false), TestNode::new(2, "Light", false)];
+        let mut actions = TestActions::new();
+        let secondary_click = |pos| egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Secondary,
+            pressed: true,
+            modifiers: egui::Modifiers::NONE,
+        };
+        let (from, to) = (egui::pos2(100.0, 18.0), egui::pos2(100.0, 45.0));
+        run_outliner(&ctx, || Outliner::new("right"), &nodes, &mut actions, vec![]);
+
+        drag_to(&ctx, || Outliner::new("right"), &nodes, &mut actions, from, to);
+        let response = run_outliner(&ctx, || Outliner::new("right"), &nodes, &mut actions, vec![egui::Event::PointerMoved(to), secondary_click(to)]);
+        assert_eq!(response.events(), &[OutlinerEvent::DragCancelled]);
+        release_at(&ctx, || Outliner::new("right"), &nodes, &mut actions, to);
+        assert!(actions.moved.is_empty());
+
+        // Can be switched off
+        let outliner = || Outliner::new("right").with_secondary_click_cancels_drag(false);
+        drag_to(&ctx, outliner, &nodes, &mut actions, from, to);
+        let response = run_outliner(&ctx, outliner, &nodes, &mut actions, vec![egui::Event::PointerMoved(to), secondary_click(to)]);
+        assert!(!response.drag_cancelled());
+        release_at(&ctx, outliner, &nodes, &mut actions, to);
+        assert_eq!(actions.moved, vec![(1, 2, DropPosition::After)]);
+    }
+
+    #[test]
+    fn test_drag_publishes_payload_and_reports_drops_outside() {
+        let ctx = egui::Context::default();
+        let nodes = vec![
+            TestNode::new(1, "Group", true).with_children(vec![TestNode::new(2, "Cube", false)]),
+            TestNode::new(3, "Light", false),
+        ];
+        let outliner = || Outliner::new("out");
+        let mut actions = TestActions::new();
+        run_outliner(&ctx, outliner, &nodes, &mut actions, vec![]);
+
+        // Other panels can see what is being dragged
+        drag_to(&ctx, outliner, &nodes, &mut actions, egui::pos2(100.0, 41.0), egui::pos2(100.0, 18.0));
+        let payload = egui::DragAndDrop::payload::<DragPayload<u64>>(&ctx).unwrap();
+        assert_eq!(payload.outliner, egui::Id::new("out"));
+        assert_eq!(payload.nodes, vec![3]);
+        assert_eq!(payload.pointer, egui::pos2(100.0, 18.0));
+
+        // Leaving the outliner forgets the drop target
+        let outside = egui::pos2(420.0, 150.0);
+        run_outliner(&ctx, outliner, &nodes, &mut actions, vec![egui::Event::PointerMoved(outside)]);
+        let state = OutlinerState::<u64>::load(&ctx, egui::Id::new("out"));
+        assert!(state.drag_drop().is_dragging());
+        assert!(state.drag_drop().hover_target.is_none());
+        assert_eq!(egui::DragAndDrop::payload::<DragPayload<u64>>(&ctx).unwrap().pointer, outside);
+
+        // Releasing there reports the nodes and the pointer instead of moving them
+        let response = release_at(&ctx, outliner, &nodes, &mut actions, outside);
+        assert_eq!(
+            response.events(),
+            &[OutlinerEvent::DroppedOutside { nodes: vec![3], pointer: outside }]
+        );
+        assert_eq!(response.dropped_outside(), Some((&[3][..], outside)));
+        assert!(!response.drag_cancelled());
+        assert!(actions.moved.is_empty());
+        let state = OutlinerState::<u64>::load(&ctx, egui::Id::new("out"));
+        assert!(!state.drag_drop().is_dragging());
+        assert!(state.dragging_nodes().is_empty());
+        assert!(!egui::DragAndDrop::has_any_payload(&ctx));
+    }
+
+    #[test]
+    fn test_drag_of_vanished_row_ends_on_release() {
+        let ctx = egui::Context::default();
+        let nodes = vec![TestNode::new(1, "Cube", false), TestNode::new(2, "Light", false)];
+        let mut actions = TestActions::new();
+        run_outliner(&ctx, || Outliner::new("vanish"), &nodes, &mut actions, vec![]);
+        drag_to(&ctx, || Outliner::new("vanish"), &nodes, &mut actions, egui::pos2(100.0, 18.0), egui::pos2(100.0, 41.0));
+
+        // The application removed the nodes mid-drag, so no row sees the release
+        let response = release_at(&ctx, || Outliner::new("vanish"), &[], &mut actions, egui::pos2(100.0, 18.0));
+        assert_eq!(response.events(), &[OutlinerEvent::DragCancelled]);
+        assert!(!OutlinerState::<u64>::load(&ctx, egui::Id::new("vanish")).drag_drop().is_dragging());
+        assert!(actions.moved.is_empty());
+    }
+
+    #[test]
+    fn test_delete_key_calls_on_delete() {
+        let ctx = egui::Context::default();
+        let nodes = vec![
+            TestNode::new(1, "Group", true).with_children(vec![TestNode::new(2, "Cube", false)]),
+            TestNode::new(3, "Light", false),
+        ];
+        let mut actions = TestActions::new();
+        actions.on_select(&2, true);
+        actions.on_select(&3, true);
+
+        run_outliner(&ctx, || Outliner::new("del"), &nodes, &mut actions, vec![]);
+        let response = run_outliner(
+            &ctx,
+            || Outliner::new("del"),
+            &nodes,
+            &mut actions,
+            vec![key_press(egui::Key::Delete)],
+        );
+        assert_eq!(response.delete_requested(), &[2, 3]);
+        assert_eq!(response.events(), &[OutlinerEvent::DeleteRequested(vec![2, 3])]);
+        assert_eq!(actions.deleted, vec![vec![2, 3]]);
+    }
+
+    #[test]
+    fn test_delete_forgets_descendant_state() {
+        let ctx = egui::Context::default();
+        let nodes = vec![
+            TestNode::new(1, "Group", true).with_children(vec![TestNode::new(2, "Cube", false)]),
+            TestNode::new(3, "Light", false),
+        ];
+        let mut actions = crate::default_actions::DefaultActions::<u64>::new();
+        actions.set_all_visible([1, 2, 3].into());
+        OutlinerActions::<TestNode>::on_select(&mut actions, &1, true);
+        OutlinerActions::<TestNode>::on_select(&mut actions, &2, true);
+        OutlinerActions::<TestNode>::on_lock_toggle(&mut actions, &2);
+
+        for events in [vec![], vec![key_press(egui::Key::Delete)]] {
+            let mut input = egui::RawInput {
+                screen_rect: Some(egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(400.0, 300.0))),
+                events,
+                ..Default::default()
+            };
+            input.events.insert(0, egui::Event::PointerMoved(egui::pos2(200.0, 250.0)));
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let response = Outliner::new("del_state").show(ui, &nodes, &mut actions);
+                    if !response.delete_requested().is_empty() {
+                        // Only the collection is reported, but its child is deleted too
+                        assert_eq!(response.delete_requested(), &[1]);
+                    }
+                });
+            });
+        }
+
+        assert!(actions.selected().is_empty());
+        assert_eq!(actions.visible(), &[3].into());
+        assert!(actions.locked().is_empty());
+    }
+
+    #[test]
+    fn test_delete_confirmation_for_collections() {
+        let ctx = egui::Context::default();
+        let nodes = vec![
+            TestNode::new(1, "Group", true).with_children(vec![TestNode::new(2, "Cube", false)]),
+        ];
+        let mut actions = TestActions::new();
+        actions.on_select(&1, true);
+        let outliner = || Outliner::new("confirm").with_delete_confirmation(true);
+
+        run_outliner(&ctx, outliner, &nodes, &mut actions, vec![]);
+        let response = run_outliner(
+            &ctx,
+            outliner,
+            &nodes,
+            &mut actions,
+            vec![key_press(egui::Key::Backspace)],
+        );
+        assert!(response.delete_requested().is_empty());
+        assert!(actions.deleted.is_empty());
+
+        // Escape cancels the pending deletion
+        run_outliner(
+            &ctx,
+            outliner,
+            &nodes,
+            &mut actions,
+            vec![key_press(egui::Key::Escape)],
+        );
+        let response = run_outliner(
+            &ctx,
+            outliner,
+            &nodes,
+            &mut actions,
+            vec![key_press(egui::Key::Enter)],
+        );
+        assert!(response.delete_requested().is_empty());
+
+        // Enter confirms it
+        run_outliner(
+            &ctx,
+            outliner,
+            &nodes,
+            &mut actions,
+            vec![key_press(egui::Key::Delete)],
+        );
+        let response = run_outliner(
+            &ctx,
+            outliner,
+            &nodes,
+            &mut actions,
+            vec![key_press(egui::Key::Enter)],
+        );
+        assert_eq!(response.delete_requested(), &[1]);
+        assert_eq!(actions.deleted, vec![vec![1, 2]]);
+    }
+
+    #[test]
+    fn test_delete_confirmation_ignores_keys_for_other_widgets() {
+        let ctx = egui::Context::default();
+        let nodes = vec![
+            TestNode::new(1, "Group", true).with_children(vec![TestNode::new(2, "Cube", false)]),
+        ];
+        let mut actions = TestActions::new();
+        actions.on_select(&1, true);
+        let mut other_text = String::new();
+        let mut frame = |events: Vec<egui::Event>, focus_other: bool| {
+            let mut input = egui::RawInput {
+                screen_rect: Some(egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(400.0, 300.0))),
+                events,
+                ..Default::default()
+            };
+            input.events.insert(0, egui::Event::PointerMoved(egui::pos2(200.0, 250.0)));
+            let mut response = None;
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let other = ui.text_edit_singleline(&mut other_text);
+                    if focus_other {
+                        other.request_focus();
+                    }
+                    response = Some(
+                        Outliner::new("confirm_focus")
+                            .with_delete_confirmation(true)
+                            .show(ui, &nodes, &mut actions),
+                    );
+                });
+            });
+            response.unwrap()
+        };
+
+        frame(vec![], false);
+        frame(vec![key_press(egui::Key::Delete)], false);
+
+        // Enter typed into another field leaves the popup open
+        frame(vec![], true);
+        let response = frame(vec![type_text("x"), key_press(egui::Key::Enter)], false);
+        assert!(response.delete_requested().is_empty());
+        let response = frame(vec![], false);
+        assert!(response.delete_requested().is_empty());
+        assert!(!OutlinerState::<u64>::load(&ctx, egui::Id::new("confirm_focus")).pending_delete().is_empty());
+
+        // Once the popup has focus again, Enter confirms
+        let response = frame(vec![key_press(egui::Key::Enter)], false);
+        assert_eq!(response.delete_requested(), &[1]);
+    }
+
+    #[test]
+    fn test_outliner_clipboard_options() {
+        let outliner = Outliner::new("test");
+        assert!(outliner.clipboard_enabled);
+        assert!(!outliner.system_clipboard);
+        assert_eq!(outliner.paste_position, DropPosition::After);
+
+        let outliner = Outliner::new("test")
+            .with_clipboard(false)
+            .with_system_clipboard(true)
+            .with_paste_position(DropPosition::Inside);
+        assert!(!outliner.clipboard_enabled);
+        assert!(outliner.system_clipboard);
+        assert_eq!(outliner.paste_position, DropPosition::Inside);
+    }
+
+    #[test]
+    fn test_outliner_with_duplicate_shortcut() {
+        let outliner = Outliner::new("test");
+        assert!(outliner.duplicate_shortcut.is_some());
+
+        let outliner = Outliner::new("test").with_duplicate_shortcut(None);
+        assert!(outliner.duplicate_shortcut.is_none());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,205 @@
+//! Options for inline renaming.
+//!
+//! This module provides [`RenameOptions`], which controls how the outliner's
+//! inline rename field normalizes the entered text and when it commits.
+
+/// Options controlling inline renaming in the outliner.
+///
+/// The entered text is normalized with [`normalize`](Self::normalize) before it
+/// is validated with [`OutlinerActions::validate_rename`](crate::OutlinerActions::validate_rename)
+/// and passed to [`OutlinerActions::on_rename`](crate::OutlinerActions::on_rename).
+///
+/// # Examples
+///
+/// ```
+/// use egui_arbor::{Outliner, RenameOptions};
+///
+/// let outliner = Outliner::new("my_outliner").with_rename_options(
+///     RenameOptions::default()
+///         .with_commit_on_blur(false)
+///         .with_collapse_whitespace(true),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenameOptions {
+    /// Commit the rename when the text field loses focus (e.g. by clicking
+    /// elsewhere). When `false`, losing focus cancels the rename.
+    ///
+    /// Default: true
+    pub commit_on_blur: bool,
+
+    /// Select the whole old name when editing starts, so typing replaces it.
+    ///
+    /// Default: true
+    pub select_all: bool,
+
+    /// Remove leading and trailing whitespace.
+    ///
+    /// Default: true
+    pub trim: bool,
+
+    /// Replace every run of whitespace with a single space.
+    ///
+    /// Default: false
+    pub collapse_whitespace: bool,
+
+    /// Accept names that are empty after normalization.
+    ///
+    /// Default: false
+    pub allow_empty: bool,
+}
+
+impl Default for RenameOptions {
+    fn default() -> Self {
+        Self {
+            commit_on_blur: true,
+            select_all: true,
+            trim: true,
+            collapse_whitespace: false,
+            allow_empty: false,
+        }
+    }
+}
+
+impl RenameOptions {
+    /// Set whether losing focus commits the rename.
+    ///
+    /// # Arguments
+    /// * `commit` - `true` to commit on focus loss, `false` to cancel
+    pub fn with_commit_on_blur(mut self, commit: bool) -> Self {
+        self.commit_on_blur = commit;
+        self
+    }
+
+    /// Set whether the old name is selected when editing starts.
+    ///
+    /// # Arguments
+    /// * `select_all` - `true` to select the whole name
+    pub fn with_select_all(mut self, select_all: bool) -> Self {
+        self.select_all = select_all;
+        self
+    }
+
+    /// Set whether leading and trailing whitespace is removed.
+    ///
+    /// # Arguments
+    /// * `trim` - `true` to trim the entered name
+    pub fn with_trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Set whether runs of whitespace are collapsed into single spaces.
+    ///
+    /// # Arguments
+    /// * `collapse` - `true` to collapse whitespace
+    pub fn with_collapse_whitespace(mut self, collapse: bool) -> Self {
+        self.collapse_whitespace = collapse;
+        self
+    }
+
+    /// Set whether empty names are accepted.
+    ///
+    /// # Arguments
+    /// * `allow` - `true` to accept empty names
+    pub fn with_allow_empty(mut self, allow: bool) -> Self {
+        self.allow_empty = allow;
+        self
+    }
+
+    /// Applies the trimming and whitespace options to the entered text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_arbor::RenameOptions;
+    ///
+    /// let options = RenameOptions::default().with_collapse_whitespace(true);
+    /// assert_eq!(options.normalize("  Main   Camera "), "Main Camera");
+    /// ```
+    pub fn normalize(&self, text: &str) -> String {
+        let text = if self.trim { text.trim() } else { text };
+
+        if self.collapse_whitespace {
+            let mut result = String::with_capacity(text.len());
+            let mut in_whitespace = false;
+            for c in text.chars() {
+                if c.is_whitespace() {
+                    if !in_whitespace {
+                        result.push(' ');
+                    }
+                    in_whitespace = true;
+                } else {
+                    result.push(c);
+                    in_whitespace = false;
+                }
+            }
+            result
+        } else {
+            text.to_string()
+        }
+    }
+
+    /// Checks a normalized name against these options.
+    ///
+    /// # Returns
+    ///
+    /// An error message if the name is empty and empty names are not allowed.
+    pub fn check(&self, normalized: &str) -> Result<(), String> {
+        if normalized.is_empty() && !self.allow_empty {
+            Err("Name cannot be empty".to_string())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_options() {
+        let options = RenameOptions::default();
+        assert!(options.commit_on_blur);
+        assert!(options.select_all);
+        assert!(options.trim);
+        assert!(!options.collapse_whitespace);
+        assert!(!options.allow_empty);
+    }
+
+    #[test]
+    fn test_builder() {
+        let options = RenameOptions::default()
+            .with_commit_on_blur(false)
+            .with_select_all(false)
+            .with_trim(false)
+            .with_collapse_whitespace(true)
+            .with_allow_empty(true);
+        assert!(!options.commit_on_blur);
+        assert!(!options.select_all);
+        assert!(!options.trim);
+        assert!(options.collapse_whitespace);
+        assert!(options.allow_empty);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let default = RenameOptions::default();
+        assert_eq!(default.normalize("  Cube  "), "Cube");
+        assert_eq!(default.normalize("Big  Cube"), "Big  Cube");
+
+        let untrimmed = RenameOptions::default().with_trim(false);
+        assert_eq!(untrimmed.normalize(" Cube "), " Cube ");
+
+        let collapsed = RenameOptions::default().with_collapse_whitespace(true);
+        assert_eq!(collapsed.normalize(" Big \t Red\nCube "), "Big Red Cube");
+    }
+
+    #[test]
+    fn test_check_empty() {
+        assert!(RenameOptions::default().check("").is_err());
+        assert!(RenameOptions::default().check("Cube").is_ok());
+        assert!(RenameOptions::default().with_allow_empty(true).check("").is_ok());
+    }
+}
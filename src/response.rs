@@ -3,6 +3,7 @@
 //! This module provides types that represent the result of rendering an outliner widget,
 //! including information about user interactions and state changes.
 
+use crate::drag_drop::DropOperation;
 use crate::traits::DropPosition;
 use std::hash::Hash;
 use std::ops::Deref;
@@ -13,6 +14,10 @@ use std::ops::Deref;
 /// outliner-specific events that occurred during the frame, such as node selection,
 /// double-clicks, context menu requests, renaming, and drag-drop operations.
 ///
+/// Every interaction is recorded in order in [`events`](Self::events). The
+/// single-slot fields (`selected`, `renamed`, `drop_event`, ...) are convenience
+/// views that hold the most recent event of their kind.
+///
 /// # Generic Parameters
 ///
 /// * `Id` - The type used to identify nodes in the outliner. Must implement
@@ -34,6 +39,12 @@ use std::ops::Deref;
 /// if let Some(drop_event) = response.drop_event() {
 ///     println!("Dropped {:?} onto {:?}", drop_event.source, drop_event.target);
 /// }
+///
+/// for event in response.events() {
+///     if let OutlinerEvent::VisibilityChanged { id, visible } = event {
+///         set_entity_visibility(id, *visible);
+///     }
+/// }
 /// ```
 #[derive(Debug)]
 pub struct OutlinerResponse<Id>
@@ -87,6 +98,35 @@ where
     ///
     /// This contains information about the source node, target node, and drop position.
     pub drop_event: Option<DropEvent<Id>>,
+
+    /// IDs of the nodes the user asked to duplicate this frame (e.g. via Ctrl/Cmd+D).
+    ///
+    /// Contains the top-most selected nodes; selected descendants of a selected
+    /// collection are omitted since duplicating the collection copies them too.
+    /// Empty when no duplication was requested.
+    pub duplicate_requested: Vec<Id>,
+
+    /// Clipboard command (cut, copy or paste) issued this frame, if any.
+    ///
+    /// Apply it to your tree with [`crate::clipboard::NodeClipboard::apply`].
+    pub clipboard: Option<ClipboardCommand<Id>>,
+
+    /// IDs of the nodes the user deleted this frame (e.g. via the Delete key).
+    ///
+    /// Contains the top-most selected nodes, after any confirmation was accepted.
+    /// [`OutlinerActions::on_delete`](crate::OutlinerActions::on_delete) has already
+    /// been called with these IDs and their descendants. Empty when nothing was
+    /// deleted.
+    pub delete_requested: Vec<Id>,
+
+    /// Every event that occurred this frame, in order.
+    pub events: Vec<OutlinerEvent<Id>>,
+
+    /// ID of the node whose row is under the pointer this frame, if any.
+    ///
+    /// Unlike the event fields, this is set on every frame the pointer rests on
+    /// a row, which makes it suitable for status bars and viewport highlights.
+    pub hovered: Option<Id>,
 }
 
 impl<Id> OutlinerResponse<Id>
@@ -118,7 +158,85 @@ where
             drag_started: None,
             dragging_nodes: Vec::new(),
             drop_event: None,
+            duplicate_requested: Vec::new(),
+            clipboard: None,
+            delete_requested: Vec::new(),
+            events: Vec::new(),
+            hovered: None,
+        }
+    }
+
+    /// Records an event and updates the matching convenience field.
+    ///
+    /// Sets [`changed`](Self::changed) for every event except context menu
+    /// requests and drag hover updates, which don't modify any state.
+    pub fn push_event(&mut self, event: OutlinerEvent<Id>) {
+        match &event {
+            OutlinerEvent::SelectionChanged { added, .. } => {
+                if let Some(id) = added.last() {
+                    self.selected = Some(id.clone());
+                }
+            }
+            OutlinerEvent::DoubleClicked(id) => self.double_clicked = Some(id.clone()),
+            OutlinerEvent::ContextMenu(id) => self.context_menu = Some(id.clone()),
+            OutlinerEvent::RenameCommitted { id, new_name } => {
+                self.renamed = Some((id.clone(), new_name.clone()));
+            }
+            OutlinerEvent::DragStarted { id, nodes } => {
+                self.drag_started = Some(id.clone());
+                self.dragging_nodes = nodes.clone();
+            }
+            OutlinerEvent::Dropped { event, nodes } => {
+                self.drop_event = Some(event.clone());
+                self.dragging_nodes = nodes.clone();
+            }
+            OutlinerEvent::DroppedOutside { nodes, .. } => self.dragging_nodes = nodes.clone(),
+            OutlinerEvent::DuplicateRequested(ids) => self.duplicate_requested = ids.clone(),
+            OutlinerEvent::Clipboard(command) => self.clipboard = Some(command.clone()),
+            OutlinerEvent::DeleteRequested(ids) => self.delete_requested = ids.clone(),
+            _ => {}
+        }
+
+        if !matches!(
+            event,
+            OutlinerEvent::ContextMenu(_) | OutlinerEvent::DragHovered { .. }
+        ) {
+            self.changed = true;
         }
+
+        self.events.push(event);
+    }
+
+    /// Returns the ID of the node whose row is under the pointer, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// if let Some(id) = response.hovered() {
+    ///     status_bar.show_details(id);
+    /// }
+    /// ```
+    #[inline]
+    pub fn hovered(&self) -> Option<&Id> {
+        self.hovered.as_ref()
+    }
+
+    /// Returns every event that occurred this frame, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// for event in response.events() {
+    ///     match event {
+    ///         OutlinerEvent::Expanded(id) => load_children(id),
+    ///         OutlinerEvent::LockChanged { id, locked } => sync_lock(id, *locked),
+    ///         _ => {}
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    pub fn events(&self) -> &[OutlinerEvent<Id>] {
+        &self.events
     }
 
     /// Returns whether any outliner state changed this frame.
@@ -236,6 +354,114 @@ where
     pub fn drop_event(&self) -> Option<&DropEvent<Id>> {
         self.drop_event.as_ref()
     }
+
+    /// Returns the target, position and files of files dropped from the
+    /// operating system this frame, if any.
+    ///
+    /// The target is `None` for files dropped below the tree
+    /// ([`DropPosition::End`]).
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// if let Some((target, position, files)) = response.files_dropped() {
+    ///     for file in files {
+    ///         import_asset(file.path.as_deref(), target, position);
+    ///     }
+    /// }
+    /// ```
+    pub fn files_dropped(&self) -> Option<(Option<&Id>, DropPosition, &[egui::DroppedFile])> {
+        self.events.iter().rev().find_map(|event| match event {
+            OutlinerEvent::FilesDropped { target, position, files } => {
+                Some((target.as_ref(), *position, files.as_slice()))
+            }
+            _ => None,
+        })
+    }
+
+    /// Returns the dragged nodes and the pointer position if a drag was
+    /// released outside the outliner this frame.
+    ///
+    /// The outliner doesn't change the tree for such drops; it's up to the
+    /// application to act on them, e.g. by placing instances in a viewport.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// if let Some((nodes, pointer)) = response.dropped_outside() {
+    ///     if viewport_rect.contains(pointer) {
+    ///         spawn_instances(nodes, pointer);
+    ///     }
+    /// }
+    /// ```
+    pub fn dropped_outside(&self) -> Option<(&[Id], egui::Pos2)> {
+        self.events.iter().rev().find_map(|event| match event {
+            OutlinerEvent::DroppedOutside { nodes, pointer } => Some((nodes.as_slice(), *pointer)),
+            _ => None,
+        })
+    }
+
+    /// Returns whether a drag was cancelled this frame.
+    ///
+    /// Drags are cancelled by Escape, a secondary click, or releasing them
+    /// over no valid drop target.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// if response.drag_cancelled() {
+    ///     restore_drag_highlights();
+    /// }
+    /// ```
+    pub fn drag_cancelled(&self) -> bool {
+        self.events.iter().any(|event| matches!(event, OutlinerEvent::DragCancelled))
+    }
+
+    /// Returns the IDs of the nodes the user asked to duplicate this frame.
+    ///
+    /// The slice is empty when no duplication was requested.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// for id in response.duplicate_requested() {
+    ///     root.duplicate_node(id, &mut next_id, &DuplicateOptions::default());
+    /// }
+    /// ```
+    #[inline]
+    pub fn duplicate_requested(&self) -> &[Id] {
+        &self.duplicate_requested
+    }
+
+    /// Returns the clipboard command issued this frame, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// if let Some(command) = response.clipboard_command() {
+    ///     NodeClipboard::apply(ui.ctx(), &mut tree, command, &mut next_id);
+    /// }
+    /// ```
+    #[inline]
+    pub fn clipboard_command(&self) -> Option<&ClipboardCommand<Id>> {
+        self.clipboard.as_ref()
+    }
+
+    /// Returns the IDs of the nodes the user deleted this frame.
+    ///
+    /// The slice is empty when nothing was deleted.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// if !response.delete_requested().is_empty() {
+    ///     remove_all_from_roots(&mut tree, response.delete_requested());
+    /// }
+    /// ```
+    #[inline]
+    pub fn delete_requested(&self) -> &[Id] {
+        &self.delete_requested
+    }
 }
 
 impl<Id> Deref for OutlinerResponse<Id>
@@ -268,7 +494,8 @@ where
 ///
 /// This struct contains information about a completed drop operation,
 /// including the source node that was dragged, the target node it was
-/// dropped onto, and the position relative to the target.
+/// dropped onto, the position relative to the target, and whether the nodes
+/// should be moved, copied or linked.
 ///
 /// # Generic Parameters
 ///
@@ -279,15 +506,18 @@ where
 ///
 /// ```ignore
 /// if let Some(drop_event) = response.drop_event() {
-///     match drop_event.position {
-///         DropPosition::Before => {
-///             insert_before(drop_event.source, drop_event.target);
+///     match (drop_event.position, drop_event.target) {
+///         (DropPosition::Before, Some(target)) => {
+///             insert_before(drop_event.source, target);
 ///         }
-///         DropPosition::After => {
-///             insert_after(drop_event.source, drop_event.target);
+///         (DropPosition::After, Some(target)) => {
+///             insert_after(drop_event.source, target);
 ///         }
-///         DropPosition::Inside => {
-///             make_child_of(drop_event.source, drop_event.target);
+///         (DropPosition::Inside, Some(target)) => {
+///             make_child_of(drop_event.source, target);
+///         }
+///         _ => {
+///             append_to_root(drop_event.source);
 ///         }
 ///     }
 /// }
@@ -301,10 +531,17 @@ where
     pub source: Id,
 
     /// The ID of the node that the source was dropped onto.
-    pub target: Id,
+    ///
+    /// `None` for drops into the empty space below the tree
+    /// ([`DropPosition::End`]), which have no target node.
+    pub target: Option<Id>,
 
     /// The position where the source should be placed relative to the target.
     pub position: DropPosition,
+
+    /// What to do with the dragged nodes, decided by the modifiers held at
+    /// drop time.
+    pub operation: DropOperation,
 }
 
 impl<Id> DropEvent<Id>
@@ -331,12 +568,215 @@ where
     pub fn new(source: Id, target: Id, position: DropPosition) -> Self {
         Self {
             source,
-            target,
+            target: Some(target),
             position,
+            operation: DropOperation::Move,
+        }
+    }
+
+    /// Creates a drop event for a drop into the empty space below the tree,
+    /// appending at the end of the root level ([`DropPosition::End`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The ID of the node that was dragged
+    pub fn at_end(source: Id) -> Self {
+        Self {
+            source,
+            target: None,
+            position: DropPosition::End,
+            operation: DropOperation::Move,
+        }
+    }
+
+    /// Sets the drop operation (moving by default).
+    pub fn with_operation(mut self, operation: DropOperation) -> Self {
+        self.operation = operation;
+        self
+    }
+}
+
+/// A single interaction reported by the outliner.
+///
+/// Events are collected in the order they happened in
+/// [`OutlinerResponse::events`]. Changes to a collection's visibility, lock or
+/// selection state that also apply to its descendants are reported once, for the
+/// node that was clicked, except for selection which lists every affected node.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OutlinerEvent<Id>
+where
+    Id: Hash + Eq + Clone,
+{
+    /// The selection changed.
+    SelectionChanged {
+        /// Nodes that became selected.
+        added: Vec<Id>,
+        /// Nodes that were deselected.
+        removed: Vec<Id>,
+    },
+
+    /// A collection was expanded.
+    Expanded(Id),
+
+    /// A collection was collapsed.
+    Collapsed(Id),
+
+    /// A node was double-clicked.
+    DoubleClicked(Id),
+
+    /// A context menu was requested for a node (usually by right-clicking).
+    ContextMenu(Id),
+
+    /// Inline renaming started for a node.
+    RenameStarted(Id),
+
+    /// Inline renaming was confirmed with a new name.
+    RenameCommitted {
+        /// The renamed node.
+        id: Id,
+        /// The name entered by the user.
+        new_name: String,
+    },
+
+    /// Inline renaming was cancelled.
+    RenameCancelled(Id),
+
+    /// A drag started.
+    DragStarted {
+        /// The node the drag started on.
+        id: Id,
+        /// Every node being dragged (the selection, if the node was selected).
+        nodes: Vec<Id>,
+    },
+
+    /// The drop target or position under the pointer changed during a drag.
+    DragHovered {
+        /// The node under the pointer, or `None` below the tree
+        /// ([`DropPosition::End`]).
+        target: Option<Id>,
+        /// Where the dragged nodes would be placed.
+        position: DropPosition,
+    },
+
+    /// Dragged nodes were dropped onto a valid target.
+    Dropped {
+        /// The drop details.
+        event: DropEvent<Id>,
+        /// Every node that was dragged.
+        nodes: Vec<Id>,
+    },
+
+    /// A drag ended without a valid drop target, or was cancelled with Escape
+    /// or a secondary click.
+    DragCancelled,
+
+    /// Dragged nodes were released outside the outliner, e.g. over a viewport
+    /// that places them.
+    DroppedOutside {
+        /// Every node that was dragged.
+        nodes: Vec<Id>,
+        /// Where the pointer was released.
+        pointer: egui::Pos2,
+    },
+
+    /// Files were dropped from the operating system onto a row, or into the
+    /// empty space below the tree ([`DropPosition::End`]).
+    FilesDropped {
+        /// The node under the pointer, or `None` below the tree
+        /// ([`DropPosition::End`]).
+        target: Option<Id>,
+        /// Where the files should be placed relative to the target.
+        position: DropPosition,
+        /// The dropped files.
+        files: Vec<egui::DroppedFile>,
+    },
+
+    /// A node's visibility was toggled.
+    VisibilityChanged {
+        /// The node whose visibility icon was clicked.
+        id: Id,
+        /// The new visibility state.
+        visible: bool,
+    },
+
+    /// A node's lock state was toggled.
+    LockChanged {
+        /// The node whose lock icon was clicked.
+        id: Id,
+        /// The new lock state.
+        locked: bool,
+    },
+
+    /// A custom action icon was clicked.
+    CustomAction {
+        /// The node whose icon was clicked.
+        id: Id,
+        /// The icon identifier from [`ActionIcon::Custom`](crate::ActionIcon::Custom).
+        icon: String,
+    },
+
+    /// Duplication of the given nodes was requested (e.g. via Ctrl/Cmd+D).
+    DuplicateRequested(Vec<Id>),
+
+    /// A clipboard command was issued.
+    Clipboard(ClipboardCommand<Id>),
+
+    /// The given nodes were deleted.
+    DeleteRequested(Vec<Id>),
+}
+
+impl<Id> OutlinerEvent<Id>
+where
+    Id: Hash + Eq + Clone,
+{
+    /// Builds a [`SelectionChanged`](Self::SelectionChanged) event from the
+    /// selected IDs before and after an interaction.
+    ///
+    /// Returns `None` if the selection did not change.
+    pub fn selection_change(before: &[Id], after: &[Id]) -> Option<Self> {
+        let added: Vec<Id> = after.iter().filter(|id| !before.contains(id)).cloned().collect();
+        let removed: Vec<Id> = before.iter().filter(|id| !after.contains(id)).cloned().collect();
+
+        if added.is_empty() && removed.is_empty() {
+            None
+        } else {
+            Some(OutlinerEvent::SelectionChanged { added, removed })
         }
     }
 }
 
+/// A clipboard command issued from the outliner's keyboard shortcuts.
+///
+/// Cut and copy act on the top-most selected nodes. Paste targets the most
+/// recently selected node, or the end of the root list when nothing is selected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClipboardCommand<Id>
+where
+    Id: Hash + Eq + Clone,
+{
+    /// Copy the given nodes (Ctrl/Cmd+C).
+    Copy(Vec<Id>),
+
+    /// Cut the given nodes (Ctrl/Cmd+X).
+    Cut(Vec<Id>),
+
+    /// Paste the clipboard contents (Ctrl/Cmd+V).
+    Paste {
+        /// The node to paste relative to, or `None` to append at the root level.
+        target: Option<Id>,
+
+        /// Where to paste relative to `target`.
+        position: DropPosition,
+
+        /// Text from the system clipboard, if the platform provided any.
+        ///
+        /// With the `serde` feature this can be decoded with
+        /// [`crate::clipboard::NodeClipboard::from_text`] to paste nodes copied
+        /// from another process.
+        text: Option<String>,
+    },
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -350,8 +790,16 @@ mod tests {
         let event = DropEvent::new(10, 20, DropPosition::Before);
         
         assert_eq!(event.source, 10);
-        assert_eq!(event.target, 20);
+        assert_eq!(event.target, Some(20));
         assert_eq!(event.position, DropPosition::Before);
+        assert_eq!(event.operation, DropOperation::Move);
+    }
+
+    #[test]
+    fn test_drop_event_with_operation() {
+        let event = DropEvent::new(1, 2, DropPosition::Inside).with_operation(DropOperation::Copy);
+        assert_eq!(event.operation, DropOperation::Copy);
+        assert_ne!(event, DropEvent::new(1, 2, DropPosition::Inside));
     }
 
     #[test]
@@ -384,10 +832,22 @@ mod tests {
         
         assert_eq!(event, cloned);
         assert_eq!(cloned.source, 5);
-        assert_eq!(cloned.target, 10);
+        assert_eq!(cloned.target, Some(10));
         assert_eq!(cloned.position, DropPosition::After);
     }
 
+    #[test]
+    fn test_selection_change() {
+        assert_eq!(OutlinerEvent::<u64>::selection_change(&[1, 2], &[2, 1]), None);
+        assert_eq!(
+            OutlinerEvent::selection_change(&[1, 2], &[2, 3]),
+            Some(OutlinerEvent::SelectionChanged {
+                added: vec![3],
+                removed: vec![1],
+            })
+        );
+    }
+
     #[test]
     fn test_drop_event_with_different_id_types() {
         let event_u64 = DropEvent::new(1u64, 2u64, DropPosition::Inside);
@@ -395,7 +855,7 @@ mod tests {
         
         let event_string = DropEvent::new("node1".to_string(), "node2".to_string(), DropPosition::Before);
         assert_eq!(event_string.source, "node1".to_string());
-        assert_eq!(event_string.target, "node2".to_string());
+        assert_eq!(event_string.target, Some("node2".to_string()));
     }
 
 }
\ No newline at end of file
@@ -0,0 +1,275 @@
+//! Display sorting for sibling nodes.
+//!
+//! This module provides [`SortMode`], which controls the order in which the
+//! outliner displays siblings without rearranging the underlying data, and
+//! [`natural_cmp`], a natural ("alphanumeric") string comparison.
+
+use std::cmp::Ordering;
+
+use crate::traits::{OutlinerActions, OutlinerNode};
+
+/// How the outliner orders sibling nodes for display.
+///
+/// Sorting only affects what is drawn (and the order used for range selection);
+/// the node slices passed to [`Outliner::show`](crate::Outliner::show) are never
+/// modified.
+///
+/// While a mode other than [`SortMode::Manual`] is active, the order within a
+/// parent is decided by the sort, so drag-drop no longer distinguishes Before
+/// from After: dropping next to a node is reported as
+/// [`DropPosition::After`](crate::DropPosition::After), meaning "into the same
+/// parent as the target".
+///
+/// # Examples
+///
+/// ```
+/// use egui_arbor::{Outliner, SortMode};
+///
+/// let outliner = Outliner::new("scene").with_sort_mode(SortMode::CollectionsFirst);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SortMode {
+    /// Show nodes in the order of the underlying data.
+    #[default]
+    Manual,
+
+    /// Sort by name using [`natural_cmp`] ("Cube 2" before "Cube 10").
+    Name,
+
+    /// Show collections before other nodes, each group sorted by name.
+    CollectionsFirst,
+
+    /// Sort with [`OutlinerActions::compare_nodes`].
+    Custom,
+}
+
+impl SortMode {
+    /// Returns `true` if nodes are shown in the order of the underlying data.
+    pub fn is_manual(&self) -> bool {
+        *self == SortMode::Manual
+    }
+
+    /// Returns the given siblings in display order.
+    ///
+    /// The sort is stable, so nodes that compare equal keep their relative order.
+    ///
+    /// # Arguments
+    ///
+    /// * `nodes` - The siblings to order
+    /// * `actions` - Provides the comparator for [`SortMode::Custom`]
+    pub fn sorted<'a, N, A>(&self, nodes: &'a [N], actions: &A) -> Vec<&'a N>
+    where
+        N: OutlinerNode,
+        A: OutlinerActions<N>,
+    {
+        let mut sorted: Vec<&N> = nodes.iter().collect();
+        match self {
+            SortMode::Manual => {}
+            SortMode::Name => sorted.sort_by(|a, b| natural_cmp(a.name(), b.name())),
+            SortMode::CollectionsFirst => sorted.sort_by(|a, b| {
+                b.is_collection()
+                    .cmp(&a.is_collection())
+                    .then_with(|| natural_cmp(a.name(), b.name()))
+            }),
+            SortMode::Custom => sorted.sort_by(|a, b| actions.compare_nodes(a, b)),
+        }
+        sorted
+    }
+}
+
+/// Compares two strings in natural order.
+///
+/// Runs of ASCII digits are compared by their numeric value and everything else
+/// is compared case-insensitively, so `"Light 2"` sorts before `"Light 10"` and
+/// `"cube"` sorts next to `"Cube"`. Strings that are otherwise equal fall back
+/// to a plain comparison to keep the order total.
+///
+/// # Examples
+///
+/// ```
+/// use egui_arbor::sort::natural_cmp;
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(natural_cmp("Cube.2", "Cube.10"), Ordering::Less);
+/// assert_eq!(natural_cmp("camera", "Cube"), Ordering::Less);
+/// ```
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a_chars = a.chars().peekable();
+    let mut b_chars = b.chars().peekable();
+
+    loop {
+        match (a_chars.peek().copied(), b_chars.peek().copied()) {
+            (None, None) => return a.cmp(b),
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let a_num = take_digits(&mut a_chars);
+                let b_num = take_digits(&mut b_chars);
+                let a_trimmed = a_num.trim_start_matches('0');
+                let b_trimmed = b_num.trim_start_matches('0');
+                let ordering = a_trimmed
+                    .len()
+                    .cmp(&b_trimmed.len())
+                    .then_with(|| a_trimmed.cmp(b_trimmed));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(x), Some(y)) => {
+                let ordering = x.to_lowercase().cmp(y.to_lowercase());
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+                a_chars.next();
+                b_chars.next();
+            }
+        }
+    }
+}
+
+/// Consumes a run of ASCII digits from the iterator.
+fn take_digits(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> String {
+    let mut digits = String::new();
+    while let Some(c) = chars.peek().copied().filter(char::is_ascii_digit) {
+        digits.push(c);
+        chars.next();
+    }
+    digits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::DropPosition;
+
+    #[derive(Debug)]
+    struct TestNode {
+        id: u64,
+        name: String,
+        is_collection: bool,
+        children: Vec<TestNode>,
+    }
+
+    impl TestNode {
+        fn new(id: u64, name: &str, is_collection: bool) -> Self {
+            Self {
+                id,
+                name: name.to_string(),
+                is_collection,
+                children: Vec::new(),
+            }
+        }
+    }
+
+    impl OutlinerNode for TestNode {
+        type Id = u64;
+
+        fn id(&self) -> Self::Id {
+            self.id
+        }
+
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        fn is_collection(&self) -> bool {
+            self.is_collection
+        }
+
+        fn children(&self) -> &[Self] {
+            &self.children
+        }
+
+        fn children_mut(&mut self) -> &mut Vec<Self> {
+            &mut self.children
+        }
+    }
+
+    /// Orders nodes by descending ID.
+    struct ReverseIdActions;
+
+    impl OutlinerActions<TestNode> for ReverseIdActions {
+        fn on_rename(&mut self, _id: &u64, _new_name: String) {}
+        fn on_move(&mut self, _id: &u64, _target: &u64, _position: DropPosition) {}
+        fn on_select(&mut self, _id: &u64, _selected: bool) {}
+        fn is_selected(&self, _id: &u64) -> bool {
+            false
+        }
+        fn is_visible(&self, _id: &u64) -> bool {
+            true
+        }
+        fn is_locked(&self, _id: &u64) -> bool {
+            false
+        }
+        fn on_visibility_toggle(&mut self, _id: &u64) {}
+        fn on_lock_toggle(&mut self, _id: &u64) {}
+        fn on_selection_toggle(&mut self, _id: &u64) {}
+        fn on_custom_action(&mut self, _id: &u64, _icon: &str) {}
+
+        fn compare_nodes(&self, a: &TestNode, b: &TestNode) -> Ordering {
+            b.id.cmp(&a.id)
+        }
+    }
+
+    fn ids(nodes: &[&TestNode]) -> Vec<u64> {
+        nodes.iter().map(|node| node.id).collect()
+    }
+
+    fn scene() -> Vec<TestNode> {
+        vec![
+            TestNode::new(1, "light 10", false),
+            TestNode::new(2, "Props", true),
+            TestNode::new(3, "Light 2", false),
+            TestNode::new(4, "Cameras", true),
+        ]
+    }
+
+    #[test]
+    fn test_natural_cmp_numbers() {
+        assert_eq!(natural_cmp("Cube 2", "Cube 10"), Ordering::Less);
+        assert_eq!(natural_cmp("Cube.010", "Cube.9"), Ordering::Greater);
+        assert_eq!(natural_cmp("a1b2", "a1b10"), Ordering::Less);
+        assert_eq!(natural_cmp("Cube", "Cube 1"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_natural_cmp_case_insensitive() {
+        assert_eq!(natural_cmp("apple", "Banana"), Ordering::Less);
+        assert_eq!(natural_cmp("Cube", "cube"), "Cube".cmp("cube"));
+        assert_eq!(natural_cmp("same", "same"), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_sort_manual_keeps_order() {
+        let nodes = scene();
+        assert_eq!(ids(&SortMode::Manual.sorted(&nodes, &ReverseIdActions)), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_sort_by_name() {
+        let nodes = scene();
+        assert_eq!(ids(&SortMode::Name.sorted(&nodes, &ReverseIdActions)), vec![4, 3, 1, 2]);
+    }
+
+    #[test]
+    fn test_sort_collections_first() {
+        let nodes = scene();
+        assert_eq!(
+            ids(&SortMode::CollectionsFirst.sorted(&nodes, &ReverseIdActions)),
+            vec![4, 2, 3, 1]
+        );
+    }
+
+    #[test]
+    fn test_sort_custom() {
+        let nodes = scene();
+        assert_eq!(ids(&SortMode::Custom.sorted(&nodes, &ReverseIdActions)), vec![4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_is_manual() {
+        assert!(SortMode::default().is_manual());
+        assert!(!SortMode::Name.is_manual());
+    }
+}
@@ -80,6 +80,21 @@ where
     #[cfg_attr(feature = "serde", serde(skip))]
     editing_text: String,
 
+    /// Whether editing started since the text field was last rendered.
+    ///
+    /// Used to focus the field and select its text once. This field is not
+    /// persisted across frames (it's transient state).
+    #[cfg_attr(feature = "serde", serde(skip))]
+    editing_started: bool,
+
+    /// Node for which renaming was requested programmatically.
+    ///
+    /// Picked up by the next [`Outliner::show`](crate::Outliner::show), which
+    /// fills in the node's current name. This field is not persisted across
+    /// frames (it's transient state).
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pending_rename: Option<Id>,
+
     /// Drag-and-drop state for this outliner.
     ///
     /// Tracks the current drag operation, hover targets, and drop positions.
@@ -107,6 +122,20 @@ where
     /// This field is not persisted across frames (it's transient state).
     #[cfg_attr(feature = "serde", serde(skip))]
     dragging_nodes: Vec<Id>,
+
+    /// IDs of nodes awaiting delete confirmation.
+    ///
+    /// Set when the user deletes a collection with children while confirmation
+    /// is enabled. This field is not persisted across frames (it's transient state).
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pending_delete: Vec<Id>,
+
+    /// Node pressed and moved that may start a drag once the outliner's drag
+    /// threshold is reached.
+    ///
+    /// This field is not persisted across frames (it's transient state).
+    #[cfg_attr(feature = "serde", serde(skip))]
+    drag_armed: Option<Id>,
 }
 
 impl<Id> Default for OutlinerState<Id>
@@ -119,10 +148,14 @@ where
             expanded: HashSet::new(),
             editing: None,
             editing_text: String::new(),
+            editing_started: false,
+            pending_rename: None,
             drag_drop: DragDropState::new(),
             last_selected: None,
             box_selection: None,
             dragging_nodes: Vec::new(),
+            pending_delete: Vec::new(),
+            drag_armed: None,
         }
     }
 }
@@ -299,6 +332,7 @@ where
     pub fn start_editing(&mut self, id: Id, initial_text: String) {
         self.editing = Some(id);
         self.editing_text = initial_text;
+        self.editing_started = true;
     }
 
     /// Stops editing the currently edited node, if any.
@@ -315,6 +349,7 @@ where
     pub fn stop_editing(&mut self) {
         self.editing = None;
         self.editing_text.clear();
+        self.editing_started = false;
     }
 
     /// Returns a mutable reference to the editing text.
@@ -324,6 +359,26 @@ where
         &mut self.editing_text
     }
 
+    /// Returns whether editing started since the last call, and resets the flag.
+    ///
+    /// The outliner uses this to focus the text field once when editing begins.
+    pub fn take_editing_started(&mut self) -> bool {
+        std::mem::take(&mut self.editing_started)
+    }
+
+    /// Requests that the outliner start renaming a node on its next frame.
+    ///
+    /// Unlike [`start_editing`](Self::start_editing), the initial text is taken
+    /// from the node itself when the outliner is shown.
+    pub fn request_rename(&mut self, id: Id) {
+        self.pending_rename = Some(id);
+    }
+
+    /// Takes the pending rename request, if any.
+    pub fn take_pending_rename(&mut self) -> Option<Id> {
+        self.pending_rename.take()
+    }
+
     /// Returns a reference to the editing text.
     pub fn editing_text(&self) -> &str {
         &self.editing_text
@@ -406,6 +461,38 @@ where
     pub fn clear_dragging_nodes(&mut self) {
         self.dragging_nodes.clear();
     }
+
+    /// Sets the nodes awaiting delete confirmation.
+    pub fn set_pending_delete(&mut self, ids: Vec<Id>) {
+        self.pending_delete = ids;
+    }
+
+    /// Returns the nodes awaiting delete confirmation.
+    pub fn pending_delete(&self) -> &[Id] {
+        &self.pending_delete
+    }
+
+    /// Takes the nodes awaiting delete confirmation, leaving none pending.
+    pub fn take_pending_delete(&mut self) -> Vec<Id> {
+        std::mem::take(&mut self.pending_delete)
+    }
+
+    /// Marks a node as pressed and moved, so it starts a drag once the drag
+    /// threshold is reached.
+    pub fn arm_drag(&mut self, id: Id) {
+        self.drag_armed = Some(id);
+    }
+
+    /// Returns whether the given node may start a drag once the drag threshold
+    /// is reached.
+    pub fn is_drag_armed(&self, id: &Id) -> bool {
+        self.drag_armed.as_ref() == Some(id)
+    }
+
+    /// Clears the node waiting for the drag threshold.
+    pub fn disarm_drag(&mut self) {
+        self.drag_armed = None;
+    }
 }
 
 #[cfg(test)]
@@ -666,6 +753,39 @@ mod tests {
         assert!(state.dragging_nodes().is_empty());
     }
 
+    #[test]
+    fn test_pending_rename() {
+        let mut state = OutlinerState::<u64>::default();
+        assert_eq!(state.take_pending_rename(), None);
+
+        state.request_rename(4);
+        assert!(!state.is_editing(&4));
+        assert_eq!(state.take_pending_rename(), Some(4));
+        assert_eq!(state.take_pending_rename(), None);
+    }
+
+    #[test]
+    fn test_editing_started_flag() {
+        let mut state = OutlinerState::<u64>::default();
+        assert!(!state.take_editing_started());
+
+        state.start_editing(1, "Cube".to_string());
+        assert!(state.take_editing_started());
+        assert!(!state.take_editing_started());
+    }
+
+    #[test]
+    fn test_pending_delete() {
+        let mut state = OutlinerState::<u64>::default();
+        assert!(state.pending_delete().is_empty());
+
+        state.set_pending_delete(vec![1, 2]);
+        assert_eq!(state.pending_delete(), &[1, 2]);
+
+        assert_eq!(state.take_pending_delete(), vec![1, 2]);
+        assert!(state.pending_delete().is_empty());
+    }
+
     #[test]
     fn test_state_isolation() {
         let mut state1 = OutlinerState::<u64>::default();
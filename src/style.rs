@@ -3,6 +3,132 @@
 //! This module provides types for customizing the visual appearance of the outliner,
 //! including colors, spacing, and icon styles.
 
+use crate::decoration::DecorationTone;
+use crate::drag_drop::DragDropVisuals;
+
+/// A complete visual theme: the outliner [`Style`] plus its [`DragDropVisuals`].
+///
+/// Themes can be built from presets or, with the `serde` feature, saved to and
+/// loaded from JSON (and RON with the `ron` feature). Missing fields fall back
+/// to their defaults, so a theme file only needs the values it changes.
+///
+/// ```rust
+/// use egui_arbor::{Outliner, Theme};
+///
+/// let outliner = Outliner::new("scene").with_theme(Theme::blender());
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
+pub struct Theme {
+    /// Spacing, sizes and colors of rows.
+    pub style: Style,
+
+    /// Colors of drag-drop feedback.
+    pub drag_drop: DragDropVisuals,
+}
+
+impl Theme {
+    /// A theme modeled on Blender's outliner. See [`Style::blender`].
+    pub fn blender() -> Self {
+        let accent = egui::Color32::from_rgb(255, 160, 60);
+        Self {
+            style: Style::blender(),
+            drag_drop: DragDropVisuals {
+                drop_line_color: accent,
+                drop_target_color: accent.gamma_multiply(0.2),
+                drag_source_color: accent.gamma_multiply(0.4),
+                corner_radius: 0.0,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// A theme modeled on VS Code's explorer. See [`Style::vscode`].
+    pub fn vscode() -> Self {
+        let accent = egui::Color32::from_rgb(0, 127, 212);
+        Self {
+            style: Style::vscode(),
+            drag_drop: DragDropVisuals {
+                drop_line_color: accent,
+                drop_line_thickness: 1.0,
+                drop_target_color: accent.gamma_multiply(0.2),
+                drag_source_color: accent.gamma_multiply(0.4),
+                corner_radius: 0.0,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// A compact theme for showing many rows at once. See [`Style::compact`].
+    pub fn compact() -> Self {
+        Self {
+            style: Style::compact(),
+            drag_drop: DragDropVisuals {
+                drop_line_thickness: 1.0,
+                corner_radius: 1.0,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// A touch-friendly theme with large rows. See [`Style::touch`].
+    pub fn touch() -> Self {
+        Self {
+            style: Style::touch(),
+            drag_drop: DragDropVisuals {
+                drop_line_thickness: 4.0,
+                corner_radius: 6.0,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Theme {
+    /// Parses a theme from JSON.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_arbor::Theme;
+    ///
+    /// let theme = Theme::from_json(r#"{ "style": { "indent": 24.0 } }"#).unwrap();
+    /// assert_eq!(theme.style.indent, 24.0);
+    /// assert_eq!(theme.style.row_height, 20.0);
+    /// ```
+    pub fn from_json(text: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(text)
+    }
+
+    /// Serializes the theme to pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+#[cfg(feature = "ron")]
+impl Theme {
+    /// Parses a theme from RON.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_arbor::{IndentGuides, Theme};
+    ///
+    /// let theme = Theme::from_ron("(style: (indent_guides: Lines))").unwrap();
+    /// assert_eq!(theme.style.indent_guides, IndentGuides::Lines);
+    /// ```
+    pub fn from_ron(text: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(text)
+    }
+
+    /// Serializes the theme to pretty-printed RON.
+    pub fn to_ron(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+    }
+}
+
 /// Style configuration for the outliner widget.
 ///
 /// Controls the visual appearance including spacing, colors, and icon sizes.
@@ -16,7 +142,13 @@
 ///     .with_indent(20.0)
 ///     .with_selection_color(Color32::from_rgb(100, 150, 200));
 /// ```
-#[derive(Debug, Clone)]
+///
+/// Start from one of the presets ([`blender`](Self::blender),
+/// [`vscode`](Self::vscode), [`compact`](Self::compact),
+/// [`touch`](Self::touch)) or, with the `serde` feature, load a whole
+/// [`Theme`] from JSON or RON.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
 pub struct Style {
     /// Indentation per hierarchy level in logical pixels.
     ///
@@ -53,10 +185,138 @@ pub struct Style {
     /// If `None`, uses egui's default hover color.
     pub hover_color: Option<egui::Color32>,
 
+    /// Optional label text color.
+    ///
+    /// If `None`, uses egui's text color for the label's interaction state.
+    pub text_color: Option<egui::Color32>,
+
+    /// Optional text color of selected labels.
+    ///
+    /// If `None`, uses egui's selection stroke color.
+    pub selected_text_color: Option<egui::Color32>,
+
+    /// Optional text color of labels with [`LabelStyle::strong`].
+    ///
+    /// If `None`, uses [`text_color`](Self::text_color), or egui's strong text
+    /// color if that isn't set either.
+    pub strong_text_color: Option<egui::Color32>,
+
+    /// Thickness of the line through labels with
+    /// [`LabelStyle::strikethrough`] in logical pixels.
+    ///
+    /// Default: 1.0
+    pub strikethrough_width: f32,
+
+    /// Optional color of action icons.
+    ///
+    /// If `None`, uses egui's text color for the icon's interaction state.
+    pub action_icon_color: Option<egui::Color32>,
+
+    /// Opacity multiplier of action icons in their off state (hidden,
+    /// unlocked, unselected).
+    ///
+    /// Default: 0.5
+    pub inactive_action_icon_opacity: f32,
+
+    /// Optional color of the drag grip in [`DragMode::Handle`](crate::DragMode::Handle)
+    /// while it isn't hovered or dragged.
+    ///
+    /// If `None`, uses egui's weak text color.
+    pub drag_handle_color: Option<egui::Color32>,
+
+    /// Corner radius of row highlights in logical pixels.
+    ///
+    /// Default: 2.0
+    pub corner_radius: f32,
+
+    /// Paint selection and hover highlights behind the whole row (indentation,
+    /// expand arrow, icon, label and action icons) instead of only the label.
+    ///
+    /// Default: false
+    pub full_row_highlight: bool,
+
+    /// Optional background of every other row (zebra striping).
+    ///
+    /// If `None`, rows are not striped.
+    pub stripe_color: Option<egui::Color32>,
+
+    /// Corner radius of row stripes in logical pixels.
+    ///
+    /// Default: 0.0
+    pub stripe_corner_radius: f32,
+
+    /// Optional outline around the active row, the most recently selected node.
+    ///
+    /// If `None`, no outline is drawn.
+    pub focus_outline: Option<egui::Stroke>,
+
+    /// Optional color of the rename field's validation error tint and message.
+    ///
+    /// If `None`, uses egui's error color.
+    pub error_color: Option<egui::Color32>,
+
+    /// Opacity multiplier of the error color tinting an invalid rename field.
+    ///
+    /// Default: 0.15
+    pub error_fill_opacity: f32,
+
+    /// Width of the outline around an invalid rename field in logical pixels.
+    ///
+    /// Default: 1.0
+    pub error_stroke_width: f32,
+
+    /// Outline of the box selection rectangle.
+    ///
+    /// Default: 1px light blue
+    pub box_selection_stroke: egui::Stroke,
+
+    /// Fill of the box selection rectangle.
+    ///
+    /// Default: translucent light blue
+    pub box_selection_fill: egui::Color32,
+
+    /// Corner radius of the box selection rectangle in logical pixels.
+    ///
+    /// Default: 0.0
+    pub box_selection_corner_radius: f32,
+
     /// Style of the expand/collapse icon.
     ///
     /// Default: `ExpandIconStyle::Arrow`
     pub expand_icon_style: ExpandIconStyle,
+
+    /// Duration of the expand/collapse animation in seconds.
+    ///
+    /// Children slide open and closed and arrow icons rotate. Zero disables
+    /// the animation.
+    ///
+    /// Default: 0.0
+    pub expand_animation_time: f32,
+
+    /// Colors of row decorations (badges).
+    ///
+    /// Default: `DecorationColors::default()`
+    pub decoration_colors: DecorationColors,
+
+    /// Guides drawn in the indentation of nested rows.
+    ///
+    /// Default: `IndentGuides::None`
+    pub indent_guides: IndentGuides,
+
+    /// Optional color of indentation guides.
+    ///
+    /// If `None`, uses egui's non-interactive widget stroke color.
+    pub indent_guide_color: Option<egui::Color32>,
+
+    /// Optional color of the guide whose children are hovered or selected.
+    ///
+    /// If `None`, uses egui's selection stroke color.
+    pub indent_guide_active_color: Option<egui::Color32>,
+
+    /// Thickness of indentation guides in logical pixels.
+    ///
+    /// Default: 1.0
+    pub indent_guide_width: f32,
 }
 
 impl Default for Style {
@@ -69,12 +329,109 @@ impl Default for Style {
             action_icon_size: 16.0,
             selection_color: Some(egui::Color32::from_rgba_unmultiplied(100, 150, 200, 100)),
             hover_color: Some(egui::Color32::from_rgba_unmultiplied(100, 150, 200, 50)),
+            text_color: None,
+            selected_text_color: None,
+            strong_text_color: None,
+            strikethrough_width: 1.0,
+            action_icon_color: None,
+            inactive_action_icon_opacity: 0.5,
+            drag_handle_color: None,
+            corner_radius: 2.0,
+            full_row_highlight: false,
+            stripe_color: None,
+            stripe_corner_radius: 0.0,
+            focus_outline: None,
+            error_color: None,
+            error_fill_opacity: 0.15,
+            error_stroke_width: 1.0,
+            box_selection_stroke: egui::Stroke::new(1.0, egui::Color32::from_rgb(100, 150, 255)),
+            box_selection_fill: egui::Color32::from_rgba_premultiplied(100, 150, 255, 30),
+            box_selection_corner_radius: 0.0,
             expand_icon_style: ExpandIconStyle::Arrow,
+            expand_animation_time: 0.0,
+            decoration_colors: DecorationColors::default(),
+            indent_guides: IndentGuides::None,
+            indent_guide_color: None,
+            indent_guide_active_color: None,
+            indent_guide_width: 1.0,
         }
     }
 }
 
 impl Style {
+    /// A dense style modeled on Blender's outliner.
+    ///
+    /// Uses tight rows, orange-tinted full-row selection, faint striping, and tree
+    /// connectors.
+    pub fn blender() -> Self {
+        Self {
+            indent: 14.0,
+            row_height: 18.0,
+            expand_icon_size: 10.0,
+            action_icon_size: 14.0,
+            selection_color: Some(egui::Color32::from_rgba_unmultiplied(255, 140, 0, 70)),
+            hover_color: Some(egui::Color32::from_rgba_unmultiplied(255, 255, 255, 20)),
+            selected_text_color: Some(egui::Color32::WHITE),
+            corner_radius: 0.0,
+            full_row_highlight: true,
+            stripe_color: Some(egui::Color32::from_rgba_unmultiplied(255, 255, 255, 5)),
+            box_selection_stroke: egui::Stroke::new(1.0, egui::Color32::from_rgb(255, 160, 60)),
+            box_selection_fill: egui::Color32::from_rgba_unmultiplied(255, 160, 60, 30),
+            indent_guides: IndentGuides::Connectors,
+            ..Self::default()
+        }
+    }
+
+    /// A style modeled on VS Code's explorer.
+    ///
+    /// Uses chevrons, blue full-row selection with a focus outline on the active
+    /// row, vertical guide lines, and a short expand animation.
+    pub fn vscode() -> Self {
+        Self {
+            indent: 12.0,
+            row_height: 22.0,
+            selection_color: Some(egui::Color32::from_rgb(4, 57, 94)),
+            hover_color: Some(egui::Color32::from_rgba_unmultiplied(255, 255, 255, 15)),
+            selected_text_color: Some(egui::Color32::WHITE),
+            corner_radius: 0.0,
+            full_row_highlight: true,
+            focus_outline: Some(egui::Stroke::new(1.0, egui::Color32::from_rgb(0, 127, 212))),
+            box_selection_stroke: egui::Stroke::new(1.0, egui::Color32::from_rgb(0, 127, 212)),
+            box_selection_fill: egui::Color32::from_rgba_unmultiplied(0, 127, 212, 30),
+            expand_icon_style: ExpandIconStyle::ChevronRight,
+            expand_animation_time: 0.1,
+            indent_guides: IndentGuides::Lines,
+            indent_guide_active_color: Some(egui::Color32::from_gray(160)),
+            ..Self::default()
+        }
+    }
+
+    /// A compact style for showing many rows at once.
+    pub fn compact() -> Self {
+        Self {
+            indent: 12.0,
+            icon_spacing: 2.0,
+            row_height: 16.0,
+            expand_icon_size: 10.0,
+            action_icon_size: 12.0,
+            corner_radius: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// A touch-friendly style with large rows and hit targets.
+    pub fn touch() -> Self {
+        Self {
+            indent: 24.0,
+            icon_spacing: 8.0,
+            row_height: 36.0,
+            expand_icon_size: 20.0,
+            action_icon_size: 28.0,
+            corner_radius: 6.0,
+            ..Self::default()
+        }
+    }
+
     /// Set the indentation per hierarchy level.
     ///
     /// # Arguments
@@ -191,6 +548,134 @@ impl Style {
         self
     }
 
+    /// Set the label text color.
+    ///
+    /// # Arguments
+    /// * `color` - The color of unselected labels
+    pub fn with_text_color(mut self, color: egui::Color32) -> Self {
+        self.text_color = Some(color);
+        self
+    }
+
+    /// Set the text color of selected labels.
+    ///
+    /// # Arguments
+    /// * `color` - The color of selected labels
+    pub fn with_selected_text_color(mut self, color: egui::Color32) -> Self {
+        self.selected_text_color = Some(color);
+        self
+    }
+
+    /// Set the corner radius of row highlights.
+    ///
+    /// # Arguments
+    /// * `radius` - Corner radius in logical pixels
+    pub fn with_corner_radius(mut self, radius: f32) -> Self {
+        self.corner_radius = radius;
+        self
+    }
+
+    /// Set whether selection and hover highlights cover the whole row.
+    ///
+    /// # Arguments
+    /// * `full_row` - `true` to highlight the whole row, `false` for only the label
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_arbor::Style;
+    ///
+    /// let style = Style::default().with_full_row_highlight(true);
+    /// ```
+    pub fn with_full_row_highlight(mut self, full_row: bool) -> Self {
+        self.full_row_highlight = full_row;
+        self
+    }
+
+    /// Set the background of every other row.
+    ///
+    /// # Arguments
+    /// * `color` - The stripe color, usually a faint translucent tint
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_arbor::Style;
+    /// use egui::Color32;
+    ///
+    /// let style = Style::default()
+    ///     .with_stripe_color(Color32::from_rgba_unmultiplied(255, 255, 255, 6));
+    /// ```
+    pub fn with_stripe_color(mut self, color: egui::Color32) -> Self {
+        self.stripe_color = Some(color);
+        self
+    }
+
+    /// Set the text color of strong labels.
+    ///
+    /// # Arguments
+    /// * `color` - The text color of labels with [`LabelStyle::strong`]
+    pub fn with_strong_text_color(mut self, color: egui::Color32) -> Self {
+        self.strong_text_color = Some(color);
+        self
+    }
+
+    /// Set the color of action icons.
+    ///
+    /// # Arguments
+    /// * `color` - The icon color
+    pub fn with_action_icon_color(mut self, color: egui::Color32) -> Self {
+        self.action_icon_color = Some(color);
+        self
+    }
+
+    /// Set the idle color of the drag grip in handle mode.
+    ///
+    /// # Arguments
+    /// * `color` - The grip color while it isn't hovered or dragged
+    pub fn with_drag_handle_color(mut self, color: egui::Color32) -> Self {
+        self.drag_handle_color = Some(color);
+        self
+    }
+
+    /// Set the outline drawn around the active row.
+    ///
+    /// # Arguments
+    /// * `stroke` - The outline stroke
+    pub fn with_focus_outline(mut self, stroke: egui::Stroke) -> Self {
+        self.focus_outline = Some(stroke);
+        self
+    }
+
+    /// Set the color of rename validation errors.
+    ///
+    /// # Arguments
+    /// * `color` - The error color
+    pub fn with_error_color(mut self, color: egui::Color32) -> Self {
+        self.error_color = Some(color);
+        self
+    }
+
+    /// Set the outline and fill of the box selection rectangle.
+    ///
+    /// # Arguments
+    /// * `stroke` - The outline of the rectangle
+    /// * `fill` - The fill of the rectangle
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_arbor::Style;
+    /// use egui::{Color32, Stroke};
+    ///
+    /// let style = Style::default().with_box_selection(
+    ///     Stroke::new(1.0, Color32::YELLOW),
+    ///     Color32::from_rgba_unmultiplied(255, 255, 0, 20),
+    /// );
+    /// ```
+    pub fn with_box_selection(mut self, stroke: egui::Stroke, fill: egui::Color32) -> Self {
+        self.box_selection_stroke = stroke;
+        self.box_selection_fill = fill;
+        self
+    }
+
     /// Set the expand/collapse icon style.
     ///
     /// # Arguments
@@ -207,6 +692,272 @@ impl Style {
         self.expand_icon_style = style;
         self
     }
+
+    /// Set the duration of the expand/collapse animation.
+    ///
+    /// # Arguments
+    /// * `seconds` - Animation time in seconds; zero disables the animation
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_arbor::Style;
+    ///
+    /// let style = Style::default().with_expand_animation_time(0.15);
+    /// ```
+    pub fn with_expand_animation_time(mut self, seconds: f32) -> Self {
+        self.expand_animation_time = seconds;
+        self
+    }
+
+    /// Set the colors of row decorations.
+    ///
+    /// # Arguments
+    /// * `colors` - The decoration colors to use
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_arbor::{DecorationColors, Style};
+    /// use egui::Color32;
+    ///
+    /// let style = Style::default().with_decoration_colors(DecorationColors {
+    ///     accent: Color32::from_rgb(255, 160, 0),
+    ///     ..Default::default()
+    /// });
+    /// ```
+    pub fn with_decoration_colors(mut self, colors: DecorationColors) -> Self {
+        self.decoration_colors = colors;
+        self
+    }
+
+    /// Set the guides drawn in the indentation of nested rows.
+    ///
+    /// # Arguments
+    /// * `guides` - The guide mode to use
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_arbor::{IndentGuides, Style};
+    ///
+    /// let style = Style::default().with_indent_guides(IndentGuides::Connectors);
+    /// ```
+    pub fn with_indent_guides(mut self, guides: IndentGuides) -> Self {
+        self.indent_guides = guides;
+        self
+    }
+
+    /// Set the color of indentation guides.
+    ///
+    /// # Arguments
+    /// * `color` - The color of inactive guides
+    pub fn with_indent_guide_color(mut self, color: egui::Color32) -> Self {
+        self.indent_guide_color = Some(color);
+        self
+    }
+
+    /// Set the color of the guide whose children are hovered or selected.
+    ///
+    /// # Arguments
+    /// * `color` - The color of the highlighted guide
+    pub fn with_indent_guide_active_color(mut self, color: egui::Color32) -> Self {
+        self.indent_guide_active_color = Some(color);
+        self
+    }
+
+    /// Set the thickness of indentation guides.
+    ///
+    /// # Arguments
+    /// * `width` - Line thickness in logical pixels
+    pub fn with_indent_guide_width(mut self, width: f32) -> Self {
+        self.indent_guide_width = width;
+        self
+    }
+}
+
+/// Guides drawn in the indentation of nested rows.
+///
+/// Guides start below an expanded collection, at the center of its
+/// expand/collapse icon. The guide of the collection whose direct children are
+/// hovered or selected is drawn in
+/// [`Style::indent_guide_active_color`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IndentGuides {
+    /// No guides, only blank indentation.
+    #[default]
+    None,
+
+    /// A vertical line spanning each expanded collection's children
+    /// (VS Code style).
+    Lines,
+
+    /// Tree connectors joining each child to its parent (`├──` and `└──`).
+    Connectors,
+}
+
+/// Per-node styling of a row's label.
+///
+/// Returned by [`OutlinerNode::label_style`](crate::OutlinerNode::label_style)
+/// to grey out disabled nodes, color errors, italicize instances and so on.
+/// Every field is optional; the default style draws the label like any other.
+///
+/// A custom [`color`](Self::color) is kept while the node is selected or hovered;
+/// the selection and hover highlights are painted over the
+/// [`background`](Self::background) tint.
+///
+/// # Example
+/// ```rust
+/// use egui_arbor::LabelStyle;
+/// use egui::Color32;
+///
+/// let prefab = LabelStyle::default()
+///     .with_color(Color32::from_rgb(110, 160, 255))
+///     .with_italics(true);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
+pub struct LabelStyle {
+    /// Text color. If `None`, uses the theme's text color (or the strong text
+    /// color when [`strong`](Self::strong) is set).
+    pub color: Option<egui::Color32>,
+
+    /// Draw the text in italics.
+    pub italics: bool,
+
+    /// Draw the text with the theme's strong (emphasized) color, see
+    /// [`Style::strong_text_color`].
+    pub strong: bool,
+
+    /// Draw a line through the text.
+    pub strikethrough: bool,
+
+    /// Tint painted behind the row's label, below selection and hover highlights.
+    pub background: Option<egui::Color32>,
+
+    /// Font family of the text. If `None`, uses the proportional font.
+    pub font_family: Option<egui::FontFamily>,
+}
+
+impl LabelStyle {
+    /// Set the text color.
+    ///
+    /// # Arguments
+    /// * `color` - The color of the label text
+    pub fn with_color(mut self, color: egui::Color32) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Set whether the text is drawn in italics.
+    ///
+    /// # Arguments
+    /// * `italics` - `true` for italic text
+    pub fn with_italics(mut self, italics: bool) -> Self {
+        self.italics = italics;
+        self
+    }
+
+    /// Set whether the text uses the strong text color.
+    ///
+    /// # Arguments
+    /// * `strong` - `true` for emphasized text
+    pub fn with_strong(mut self, strong: bool) -> Self {
+        self.strong = strong;
+        self
+    }
+
+    /// Set whether a line is drawn through the text.
+    ///
+    /// # Arguments
+    /// * `strikethrough` - `true` to strike the text through
+    pub fn with_strikethrough(mut self, strikethrough: bool) -> Self {
+        self.strikethrough = strikethrough;
+        self
+    }
+
+    /// Set the background tint behind the label.
+    ///
+    /// # Arguments
+    /// * `color` - The tint color, usually translucent
+    pub fn with_background(mut self, color: egui::Color32) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Set the font family of the text.
+    ///
+    /// # Arguments
+    /// * `family` - The font family, e.g. `egui::FontFamily::Monospace`
+    pub fn with_font_family(mut self, family: egui::FontFamily) -> Self {
+        self.font_family = Some(family);
+        self
+    }
+}
+
+/// Colors used to paint row decorations.
+///
+/// Each [`DecorationTone`] maps to one color; pills are filled with it, dots and
+/// icons are drawn in it, and pill text uses [`text`](Self::text).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
+pub struct DecorationColors {
+    /// Color of [`DecorationTone::Neutral`] decorations and counts.
+    ///
+    /// Default: gray
+    pub neutral: egui::Color32,
+
+    /// Color of [`DecorationTone::Accent`] decorations.
+    ///
+    /// Default: blue
+    pub accent: egui::Color32,
+
+    /// Color of [`DecorationTone::Success`] decorations.
+    ///
+    /// Default: green
+    pub success: egui::Color32,
+
+    /// Color of [`DecorationTone::Warning`] decorations.
+    ///
+    /// Default: amber
+    pub warning: egui::Color32,
+
+    /// Color of [`DecorationTone::Error`] decorations.
+    ///
+    /// Default: red
+    pub error: egui::Color32,
+
+    /// Color of the text inside pills.
+    ///
+    /// Default: white
+    pub text: egui::Color32,
+}
+
+impl Default for DecorationColors {
+    fn default() -> Self {
+        Self {
+            neutral: egui::Color32::from_gray(110),
+            accent: egui::Color32::from_rgb(70, 130, 210),
+            success: egui::Color32::from_rgb(70, 160, 90),
+            warning: egui::Color32::from_rgb(220, 160, 40),
+            error: egui::Color32::from_rgb(210, 70, 70),
+            text: egui::Color32::WHITE,
+        }
+    }
+}
+
+impl DecorationColors {
+    /// Get the color for a decoration tone.
+    ///
+    /// # Returns
+    /// The color decorations of this tone are painted with.
+    pub fn color(&self, tone: DecorationTone) -> egui::Color32 {
+        match tone {
+            DecorationTone::Neutral => self.neutral,
+            DecorationTone::Accent => self.accent,
+            DecorationTone::Success => self.success,
+            DecorationTone::Warning => self.warning,
+            DecorationTone::Error => self.error,
+        }
+    }
 }
 
 /// Style of the expand/collapse icon.
@@ -214,6 +965,7 @@ impl Style {
 /// Determines the visual appearance of the icon used to expand and collapse
 /// tree nodes in the outliner.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ExpandIconStyle {
     /// Simple arrow style (▶ when collapsed, ▼ when expanded).
     Arrow,
@@ -244,6 +996,14 @@ pub enum ExpandIconStyle {
 }
 
 impl ExpandIconStyle {
+    /// Returns whether the expanded state is a rotated collapsed glyph.
+    ///
+    /// Animated outliners rotate these styles' collapsed glyph instead of
+    /// switching to the expanded one.
+    pub fn rotates(&self) -> bool {
+        matches!(self, ExpandIconStyle::Arrow | ExpandIconStyle::ChevronRight)
+    }
+
     /// Get the string representation for the collapsed state.
     ///
     /// # Returns
@@ -271,8 +1031,74 @@ impl ExpandIconStyle {
     }
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for ExpandIconStyle {
     fn default() -> Self {
         Self::Arrow
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets_differ_from_default() {
+        let default = Style::default();
+        for preset in [Style::blender(), Style::vscode(), Style::compact(), Style::touch()] {
+            assert_ne!(preset, default);
+        }
+        assert!(Style::compact().row_height < default.row_height);
+        assert!(Style::touch().row_height > default.row_height);
+    }
+
+    #[test]
+    fn test_theme_presets_use_style_presets() {
+        assert_eq!(Theme::default().style, Style::default());
+        assert_eq!(Theme::blender().style, Style::blender());
+        assert_eq!(Theme::vscode().style, Style::vscode());
+        assert_eq!(Theme::compact().style, Style::compact());
+        assert_eq!(Theme::touch().style, Style::touch());
+    }
+
+    #[test]
+    fn test_expand_icon_rotates() {
+        assert!(ExpandIconStyle::Arrow.rotates());
+        assert!(ExpandIconStyle::ChevronRight.rotates());
+        assert!(!ExpandIconStyle::PlusMinus.rotates());
+    }
+
+    #[test]
+    fn test_decoration_colors() {
+        let colors = DecorationColors::default();
+        assert_eq!(colors.color(DecorationTone::Warning), colors.warning);
+        assert_eq!(colors.color(DecorationTone::Neutral), colors.neutral);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_theme_json_round_trip() {
+        let theme = Theme::vscode();
+        let json = theme.to_json().unwrap();
+        assert_eq!(Theme::from_json(&json).unwrap(), theme);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_theme_json_partial() {
+        let theme = Theme::from_json(r#"{ "drag_drop": { "drop_line_thickness": 3.0 } }"#).unwrap();
+        assert_eq!(theme.drag_drop.drop_line_thickness, 3.0);
+        assert_eq!(theme.drag_drop.corner_radius, 2.0);
+        assert_eq!(theme.style, Style::default());
+
+        assert!(Theme::from_json("{ \"style\": 5 }").is_err());
+    }
+
+    #[cfg(feature = "ron")]
+    #[test]
+    fn test_theme_ron_round_trip() {
+        let theme = Theme::blender();
+        let ron = theme.to_ron().unwrap();
+        assert_eq!(Theme::from_ron(&ron).unwrap(), theme);
+    }
+}
@@ -5,6 +5,10 @@
 
 use std::hash::Hash;
 
+use crate::decoration::Decoration;
+use crate::drag_drop::DropOperation;
+use crate::style::LabelStyle;
+
 /// Represents a node in the outliner hierarchy.
 ///
 /// Users implement this trait on their own data structures to integrate with
@@ -129,6 +133,122 @@ pub trait OutlinerNode: Sized {
             ActionIcon::Selection,
         ]
     }
+
+    /// Returns whether hovering this node's label shows a tooltip.
+    ///
+    /// When this returns `true`, [`tooltip_ui`](Self::tooltip_ui) is called to
+    /// fill the hover popup. The default implementation returns `false`.
+    fn has_tooltip(&self) -> bool {
+        false
+    }
+
+    /// Renders the contents of the label's hover tooltip.
+    ///
+    /// Only called when [`has_tooltip`](Self::has_tooltip) returns `true`. Any egui
+    /// UI can be shown here. The default implementation shows nothing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use egui_arbor::OutlinerNode;
+    /// # struct MyNode { name: String, vertices: usize, children: Vec<MyNode> }
+    /// # impl OutlinerNode for MyNode {
+    /// #     type Id = u64;
+    /// #     fn id(&self) -> Self::Id { 0 }
+    /// #     fn name(&self) -> &str { &self.name }
+    /// #     fn is_collection(&self) -> bool { false }
+    /// #     fn children(&self) -> &[Self] { &self.children }
+    /// #     fn children_mut(&mut self) -> &mut Vec<Self> { &mut self.children }
+    /// fn has_tooltip(&self) -> bool {
+    ///     true
+    /// }
+    ///
+    /// fn tooltip_ui(&self, ui: &mut egui::Ui) {
+    ///     ui.strong(&self.name);
+    ///     ui.label(format!("{} vertices", self.vertices));
+    /// }
+    /// # }
+    /// ```
+    fn tooltip_ui(&self, ui: &mut egui::Ui) {
+        let _ = ui;
+    }
+
+    /// Returns the tooltip text for one of this node's action icons.
+    ///
+    /// Return `Some` to replace the outliner's built-in text (such as "Hide" or
+    /// "Lock") or the `tooltip` of an [`ActionIcon::Custom`]. The default
+    /// implementation returns `None`.
+    fn action_tooltip(&self, icon: &ActionIcon) -> Option<String> {
+        let _ = icon;
+        None
+    }
+
+    /// Returns the badges painted right after this node's label.
+    ///
+    /// Use decorations for child counts, warning/error markers or "modified"
+    /// dots; their colors come from
+    /// [`Style::decoration_colors`](crate::Style::decoration_colors). The default
+    /// implementation returns no decorations.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use egui_arbor::{Decoration, DecorationTone, OutlinerNode};
+    /// # struct MyNode { name: String, modified: bool, warning: Option<String>, children: Vec<MyNode> }
+    /// # impl OutlinerNode for MyNode {
+    /// #     type Id = u64;
+    /// #     fn id(&self) -> Self::Id { 0 }
+    /// #     fn name(&self) -> &str { &self.name }
+    /// #     fn is_collection(&self) -> bool { false }
+    /// #     fn children(&self) -> &[Self] { &self.children }
+    /// #     fn children_mut(&mut self) -> &mut Vec<Self> { &mut self.children }
+    /// fn decorations(&self) -> Vec<Decoration> {
+    ///     let mut decorations = Vec::new();
+    ///     if self.modified {
+    ///         decorations.push(Decoration::dot(DecorationTone::Accent));
+    ///     }
+    ///     if let Some(warning) = &self.warning {
+    ///         decorations.push(Decoration::icon("⚠", DecorationTone::Warning).with_tooltip(warning));
+    ///     }
+    ///     decorations
+    /// }
+    /// # }
+    /// ```
+    fn decorations(&self) -> Vec<Decoration> {
+        Vec::new()
+    }
+
+    /// Returns the styling of this node's label.
+    ///
+    /// Use this to grey out disabled nodes, draw errors in red or italicize
+    /// instanced nodes. The default implementation returns
+    /// [`LabelStyle::default()`], which draws the label with the theme's colors.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use egui_arbor::{LabelStyle, OutlinerNode};
+    /// # struct MyNode { name: String, enabled: bool, instanced: bool, children: Vec<MyNode> }
+    /// # impl OutlinerNode for MyNode {
+    /// #     type Id = u64;
+    /// #     fn id(&self) -> Self::Id { 0 }
+    /// #     fn name(&self) -> &str { &self.name }
+    /// #     fn is_collection(&self) -> bool { false }
+    /// #     fn children(&self) -> &[Self] { &self.children }
+    /// #     fn children_mut(&mut self) -> &mut Vec<Self> { &mut self.children }
+    /// fn label_style(&self) -> LabelStyle {
+    ///     let style = LabelStyle::default().with_italics(self.instanced);
+    ///     if self.enabled {
+    ///         style
+    ///     } else {
+    ///         style.with_color(egui::Color32::GRAY)
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    fn label_style(&self) -> LabelStyle {
+        LabelStyle::default()
+    }
 }
 
 /// Handles user interactions and state changes for outliner nodes.
@@ -229,6 +349,22 @@ pub trait OutlinerActions<N: OutlinerNode> {
     /// * `new_name` - The new name entered by the user
     fn on_rename(&mut self, id: &N::Id, new_name: String);
 
+    /// Checks a name entered during inline renaming.
+    ///
+    /// Called every frame while the user edits, with the name already normalized
+    /// according to [`RenameOptions`](crate::RenameOptions). Returning an error
+    /// tints the text field red, shows the message below it and prevents the
+    /// rename from being committed. The default implementation accepts any name.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - The unique identifier of the node being renamed
+    /// * `new_name` - The normalized name entered by the user
+    fn validate_rename(&self, id: &N::Id, new_name: &str) -> Result<(), String> {
+        let _ = (id, new_name);
+        Ok(())
+    }
+
     /// Called when a node is moved via drag-and-drop.
     ///
     /// This is triggered when the user successfully completes a drag-drop operation.
@@ -241,6 +377,78 @@ pub trait OutlinerActions<N: OutlinerNode> {
     /// * `position` - Where to place the node relative to the target
     fn on_move(&mut self, id: &N::Id, target: &N::Id, position: DropPosition);
 
+    /// Called when a node is dropped with [`DropOperation::Copy`](crate::DropOperation::Copy)
+    /// (Ctrl/Cmd held at drop time).
+    ///
+    /// Called once with every dragged node, like [`on_drop_at_end`](Self::on_drop_at_end).
+    ///
+    /// Copies should be placed at `target`/`position`, in the order of `ids`,
+    /// while the originals stay where they are; [`tree_ops::copy_into_roots`](crate::tree_ops::copy_into_roots)
+    /// does this for trees implementing [`TreeOperations`](crate::tree_ops::TreeOperations).
+    /// The default implementation does nothing.
+    ///
+    /// # Parameters
+    ///
+    /// * `ids` - The IDs of all dragged nodes, in display order
+    /// * `target` - The ID of the target node
+    /// * `position` - Where to place the copies relative to the target
+    fn on_copy(&mut self, ids: &[N::Id], target: &N::Id, position: DropPosition) {
+        let _ = (ids, target, position);
+    }
+
+    /// Called when a node is dropped with [`DropOperation::Link`](crate::DropOperation::Link)
+    /// (Alt held at drop time).
+    ///
+    /// Called once with every dragged node, like [`on_drop_at_end`](Self::on_drop_at_end).
+    ///
+    /// What a link is depends on the application, e.g. an instance sharing the
+    /// original's data. The default implementation does nothing.
+    ///
+    /// # Parameters
+    ///
+    /// * `ids` - The IDs of all dragged nodes, in display order
+    /// * `target` - The ID of the target node
+    /// * `position` - Where to place the links relative to the target
+    fn on_link(&mut self, ids: &[N::Id], target: &N::Id, position: DropPosition) {
+        let _ = (ids, target, position);
+    }
+
+    /// Called when nodes are dropped into the empty space below the tree, to be
+    /// appended at the end of the root level ([`DropPosition::End`]).
+    ///
+    /// Unlike [`on_move`](Self::on_move), [`on_copy`](Self::on_copy) and
+    /// [`on_link`](Self::on_link) there is no target node, so this is called
+    /// with the operation chosen by the modifiers.
+    /// The default implementation does nothing.
+    ///
+    /// # Parameters
+    ///
+    /// * `ids` - The IDs of all dragged nodes, in display order
+    /// * `operation` - Whether to move, copy or link the nodes
+    fn on_drop_at_end(&mut self, ids: &[N::Id], operation: DropOperation) {
+        let _ = (ids, operation);
+    }
+
+    /// Checks whether the dragged nodes may be dropped at a location.
+    ///
+    /// Called while hovering during a drag, once the built-in checks (no drops
+    /// onto any dragged node or into their descendants, Inside only on
+    /// collections) have passed. Returning an error fades the drop indicator
+    /// by [`DragDropVisuals::invalid_target_opacity`](crate::DragDropVisuals::invalid_target_opacity),
+    /// shows the message next to the pointer and prevents the drop. The
+    /// default implementation accepts every drop.
+    ///
+    /// # Parameters
+    ///
+    /// * `sources` - All dragged nodes (the whole selection for multi-drag)
+    /// * `target` - The node the drop is relative to, or `None` for
+    ///   [`DropPosition::End`]
+    /// * `position` - Where the nodes would be placed relative to the target
+    fn can_drop(&self, sources: &[&N], target: Option<&N>, position: DropPosition) -> Result<(), String> {
+        let _ = (sources, target, position);
+        Ok(())
+    }
+
     /// Called when a node's selection state changes.
     ///
     /// This is triggered when the user clicks on a node or uses keyboard navigation
@@ -311,6 +519,127 @@ pub trait OutlinerActions<N: OutlinerNode> {
     /// * `id` - The unique identifier of the node
     /// * `icon` - The icon identifier from the custom action icon
     fn on_custom_action(&mut self, id: &N::Id, icon: &str);
+
+    /// Called when the user deletes nodes (Delete or Backspace on the selection).
+    ///
+    /// `ids` lists every deleted node: each top-most selected node followed by
+    /// all of its descendants, so state kept per node can be dropped for the
+    /// whole subtree. Only the top-most nodes are reported in
+    /// [`OutlinerResponse::delete_requested`](crate::OutlinerResponse::delete_requested);
+    /// the tree itself can be updated with
+    /// [`TreeOperations::remove_nodes`](crate::tree_ops::TreeOperations::remove_nodes).
+    ///
+    /// The default implementation does nothing.
+    ///
+    /// # Parameters
+    ///
+    /// * `ids` - The unique identifiers of the deleted nodes and their descendants
+    fn on_delete(&mut self, ids: &[N::Id]) {
+        let _ = ids;
+    }
+
+    /// Compares two sibling nodes for display when sorting with
+    /// [`SortMode::Custom`](crate::SortMode::Custom).
+    ///
+    /// The default implementation compares names with
+    /// [`natural_cmp`](crate::sort::natural_cmp).
+    ///
+    /// # Parameters
+    ///
+    /// * `a` - The first node
+    /// * `b` - The second node
+    fn compare_nodes(&self, a: &N, b: &N) -> std::cmp::Ordering {
+        crate::sort::natural_cmp(a.name(), b.name())
+    }
+
+    /// Returns the width to reserve for a node's inline widget, in logical pixels.
+    ///
+    /// Rows whose width is greater than zero get an area of this width between
+    /// the label and the action icons, filled by
+    /// [`row_widget_ui`](Self::row_widget_ui); the label shrinks to make room.
+    /// The default implementation returns `0.0` (no widget).
+    ///
+    /// # Parameters
+    ///
+    /// * `node` - The node whose row is being laid out
+    fn row_widget_width(&self, node: &N) -> f32 {
+        let _ = node;
+        0.0
+    }
+
+    /// Renders custom widgets (sliders, color swatches, progress bars, ...) inline
+    /// in a node's row.
+    ///
+    /// Only called for nodes with a positive
+    /// [`row_widget_width`](Self::row_widget_width). Interacting with these
+    /// widgets does not select or drag the row. The default implementation does
+    /// nothing.
+    ///
+    /// # Parameters
+    ///
+    /// * `ui` - A left-to-right UI of the reserved width and the row's height
+    /// * `node` - The node whose row is being rendered
+    /// * `row` - Information about the row
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// fn row_widget_width(&self, node: &SceneNode) -> f32 {
+    ///     if node.has_lod() { 80.0 } else { 0.0 }
+    /// }
+    ///
+    /// fn row_widget_ui(&mut self, ui: &mut egui::Ui, node: &SceneNode, _row: &RowInfo) {
+    ///     let lod = self.lod_levels.entry(node.id).or_insert(0);
+    ///     ui.add(egui::Slider::new(lod, 0..=3).show_value(false));
+    /// }
+    /// ```
+    fn row_widget_ui(&mut self, ui: &mut egui::Ui, node: &N, row: &RowInfo) {
+        let _ = (ui, node, row);
+    }
+
+    /// Returns whether [`drag_preview_ui`](Self::drag_preview_ui) draws the
+    /// preview that follows the pointer while dragging.
+    ///
+    /// The default implementation returns `false`, which shows the dragged
+    /// node's name (or "N items") styled by
+    /// [`DragDropVisuals`](crate::DragDropVisuals).
+    fn has_drag_preview(&self) -> bool {
+        false
+    }
+
+    /// Renders a custom drag preview.
+    ///
+    /// Only called when [`has_drag_preview`](Self::has_drag_preview) returns
+    /// `true`. The UI is placed next to the pointer on top of everything else
+    /// and doesn't receive input. The default implementation shows nothing.
+    ///
+    /// # Parameters
+    ///
+    /// * `ui` - The preview's UI
+    /// * `nodes` - The nodes being dragged
+    fn drag_preview_ui(&mut self, ui: &mut egui::Ui, nodes: &[&N]) {
+        let _ = (ui, nodes);
+    }
+}
+
+/// Information about the row being rendered, passed to
+/// [`OutlinerActions::row_widget_ui`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RowInfo {
+    /// Nesting depth of the node (0 for root nodes).
+    pub depth: usize,
+
+    /// Whether the node is selected.
+    pub is_selected: bool,
+
+    /// Whether the node is an expanded collection.
+    pub is_expanded: bool,
+
+    /// Whether the node is being renamed.
+    pub is_editing: bool,
+
+    /// Width reserved for the widget in logical pixels.
+    pub width: f32,
 }
 
 /// The type of icon to display next to a node.
@@ -374,7 +703,11 @@ pub enum ActionIcon {
 ///
 /// This enum is used in [`OutlinerActions::on_move`] to indicate the desired
 /// position of the dragged node relative to the drop target.
+///
+/// The enum is non-exhaustive: matches need a wildcard arm so new positions
+/// can be added without breaking them.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum DropPosition {
     /// Place the node before the target (as a sibling)
     Before,
@@ -386,4 +719,12 @@ pub enum DropPosition {
     ///
     /// This is only valid if the target is a collection node.
     Inside,
+
+    /// Append the node at the end of the root level.
+    ///
+    /// Reported when dropping into the empty space below the tree, which also
+    /// works for an empty tree. These drops have no target node: they go to
+    /// [`OutlinerActions::on_drop_at_end`] and are reported with a `None`
+    /// [`DropEvent::target`](crate::DropEvent::target).
+    End,
 }
\ No newline at end of file
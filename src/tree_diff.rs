@@ -0,0 +1,671 @@
+//! Structural diffs between two versions of a tree.
+//!
+//! This module compares two lists of root nodes by ID and reports what changed,
+//! which is useful for keeping an external model (such as an ECS world or a
+//! backend) in sync with the tree shown in the outliner. A [`TreeDiff`] can also
+//! be applied as a patch to turn the old tree into the new one.
+//!
+//! # Examples
+//!
+//! ```
+//! use egui_arbor::{OutlinerNode, tree_ops::TreeOperations};
+//! use egui_arbor::tree_diff::{diff, TreeChange};
+//!
+//! #[derive(Clone, Debug, PartialEq)]
+//! struct MyNode {
+//!     id: u64,
+//!     name: String,
+//!     children: Vec<MyNode>,
+//! }
+//!
+//! impl OutlinerNode for MyNode {
+//!     type Id = u64;
+//!     fn id(&self) -> Self::Id { self.id }
+//!     fn name(&self) -> &str { &self.name }
+//!     fn is_collection(&self) -> bool { true }
+//!     fn children(&self) -> &[Self] { &self.children }
+//!     fn children_mut(&mut self) -> &mut Vec<Self> { &mut self.children }
+//! }
+//!
+//! impl TreeOperations for MyNode {
+//!     fn set_name(&mut self, name: String) -> bool {
+//!         self.name = name;
+//!         true
+//!     }
+//! }
+//!
+//! let node = |id, name: &str| MyNode { id, name: name.into(), children: vec![] };
+//! let old = vec![node(1, "Cube"), node(2, "Light")];
+//! let new = vec![node(2, "Sun"), node(1, "Cube")];
+//!
+//! let changes = diff(&old, &new);
+//! assert!(changes.changes().iter().any(|c| matches!(c, TreeChange::Renamed { id: 2, .. })));
+//!
+//! let mut patched = old.clone();
+//! assert!(changes.apply(&mut patched));
+//! assert_eq!(patched, new);
+//! ```
+
+use crate::traits::OutlinerNode;
+use crate::tree_ops::TreeOperations;
+use std::collections::{HashMap, HashSet};
+
+/// A single change between two versions of a tree.
+///
+/// Parents are `None` for root nodes. Indices are positions among the parent's
+/// children (or among the roots).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "N: serde::Serialize, N::Id: serde::Serialize",
+        deserialize = "N: serde::Deserialize<'de>, N::Id: serde::Deserialize<'de>"
+    ))
+)]
+pub enum TreeChange<N: OutlinerNode> {
+    /// A node exists only in the new tree.
+    ///
+    /// `node` is a copy of the new node without its children; every new
+    /// descendant is reported as its own `Added` change.
+    Added {
+        /// The ID of the added node
+        id: N::Id,
+        /// The parent in the new tree
+        parent: Option<N::Id>,
+        /// The index in the new tree
+        index: usize,
+        /// The added node, without children
+        node: N,
+    },
+
+    /// A node exists only in the old tree.
+    ///
+    /// Every removed descendant is reported as its own `Removed` change.
+    Removed {
+        /// The ID of the removed node
+        id: N::Id,
+        /// The parent in the old tree
+        parent: Option<N::Id>,
+        /// The index in the old tree
+        index: usize,
+    },
+
+    /// A node's name changed.
+    Renamed {
+        /// The ID of the renamed node
+        id: N::Id,
+        /// The name in the old tree
+        old_name: String,
+        /// The name in the new tree
+        new_name: String,
+    },
+
+    /// A node moved to a different parent.
+    Moved {
+        /// The ID of the moved node
+        id: N::Id,
+        /// The parent in the old tree
+        old_parent: Option<N::Id>,
+        /// The index in the old tree
+        old_index: usize,
+        /// The parent in the new tree
+        new_parent: Option<N::Id>,
+        /// The index in the new tree
+        new_index: usize,
+    },
+
+    /// A node changed position among the siblings it kept.
+    ///
+    /// Only the smallest set of nodes that explains the new order is reported:
+    /// siblings that merely shifted because of insertions or removals are not.
+    Reordered {
+        /// The ID of the reordered node
+        id: N::Id,
+        /// The parent in both trees
+        parent: Option<N::Id>,
+        /// The index in the old tree
+        old_index: usize,
+        /// The index in the new tree
+        new_index: usize,
+    },
+}
+
+impl<N: OutlinerNode> TreeChange<N> {
+    /// Returns the ID of the node this change refers to.
+    pub fn id(&self) -> &N::Id {
+        match self {
+            TreeChange::Added { id, .. }
+            | TreeChange::Removed { id, .. }
+            | TreeChange::Renamed { id, .. }
+            | TreeChange::Moved { id, .. }
+            | TreeChange::Reordered { id, .. } => id,
+        }
+    }
+}
+
+/// The list of changes between two versions of a tree.
+///
+/// Created by [`diff`]. Changes are ordered so they can be replayed: removals
+/// first (in old-tree order), then renames and position changes in new-tree
+/// order, parents before their children.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "N: serde::Serialize, N::Id: serde::Serialize",
+        deserialize = "N: serde::Deserialize<'de>, N::Id: serde::Deserialize<'de>"
+    ))
+)]
+pub struct TreeDiff<N: OutlinerNode> {
+    changes: Vec<TreeChange<N>>,
+}
+
+impl<N: OutlinerNode> TreeDiff<N> {
+    /// Returns the changes in replay order.
+    pub fn changes(&self) -> &[TreeChange<N>] {
+        &self.changes
+    }
+
+    /// Consumes the diff and returns its changes.
+    pub fn into_changes(self) -> Vec<TreeChange<N>> {
+        self.changes
+    }
+
+    /// Returns `true` if both trees had the same structure and names.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns the number of changes.
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+}
+
+impl<N: TreeOperations> TreeDiff<N> {
+    /// Applies the changes to a list of root nodes.
+    ///
+    /// Applied to the old tree this produces the new tree. Renames go through
+    /// [`TreeOperations::set_name`], so node types that don't implement it cannot
+    /// be renamed by a patch.
+    ///
+    /// # Returns
+    ///
+    /// `true` if every change was applied, `false` if some change referred to a
+    /// node or parent that could not be found (the remaining changes are still
+    /// applied).
+    pub fn apply(&self, roots: &mut Vec<N>) -> bool {
+        let mut all_applied = true;
+
+        for change in &self.changes {
+            if let TreeChange::Renamed { id, new_name, .. } = change {
+                all_applied &= roots
+                    .iter_mut()
+                    .find_map(|root| root.find_node_mut(id))
+                    .is_some_and(|node| node.set_name(new_name.clone()));
+            }
+        }
+
+        // Take out every node that is removed or changes position, including
+        // nodes nested inside other taken nodes
+        let taken_ids: HashSet<&N::Id> = self
+            .changes
+            .iter()
+            .filter(|change| {
+                matches!(
+                    change,
+                    TreeChange::Removed { .. } | TreeChange::Moved { .. } | TreeChange::Reordered { .. }
+                )
+            })
+            .map(TreeChange::id)
+            .collect();
+        let mut taken = HashMap::new();
+        take_nodes(roots, &taken_ids, &mut taken);
+        all_applied &= taken.len() == taken_ids.len();
+
+        // Insert in new-tree order so parents and earlier siblings are in place
+        for change in &self.changes {
+            let (node, parent, index) = match change {
+                TreeChange::Added { parent, index, node, .. } => (Some(node.clone()), parent, *index),
+                TreeChange::Moved { id, new_parent, new_index, .. } => (taken.remove(id), new_parent, *new_index),
+                TreeChange::Reordered { id, parent, new_index, .. } => (taken.remove(id), parent, *new_index),
+                TreeChange::Removed { .. } | TreeChange::Renamed { .. } => continue,
+            };
+
+            all_applied &= match node {
+                Some(node) => insert_at(roots, parent.as_ref(), index, node),
+                None => false,
+            };
+        }
+
+        all_applied
+    }
+}
+
+/// Computes the changes that turn `old` into `new`.
+///
+/// Nodes are matched by ID. Each node is reported at most once per kind of
+/// change; a node that was renamed and moved produces both a
+/// [`TreeChange::Renamed`] and a [`TreeChange::Moved`].
+///
+/// # Arguments
+///
+/// * `old` - The root nodes of the previous version
+/// * `new` - The root nodes of the current version
+pub fn diff<N>(old: &[N], new: &[N]) -> TreeDiff<N>
+where
+    N: OutlinerNode + Clone,
+{
+    let mut old_index = TreeIndex::default();
+    old_index.collect(old, None);
+    let mut new_index = TreeIndex::default();
+    new_index.collect(new, None);
+
+    let mut changes = Vec::new();
+
+    for (id, location) in &old_index.order {
+        if !new_index.locations.contains_key(id) {
+            changes.push(TreeChange::Removed {
+                id: id.clone(),
+                parent: location.parent.clone(),
+                index: location.index,
+            });
+        }
+    }
+
+    let reordered = reordered_ids(&old_index, &new_index);
+
+    for (id, location) in &new_index.order {
+        let Some(old_location) = old_index.locations.get(id) else {
+            let mut node = location.node.clone();
+            node.children_mut().clear();
+            changes.push(TreeChange::Added {
+                id: id.clone(),
+                parent: location.parent.clone(),
+                index: location.index,
+                node,
+            });
+            continue;
+        };
+
+        if old_location.node.name() != location.node.name() {
+            changes.push(TreeChange::Renamed {
+                id: id.clone(),
+                old_name: old_location.node.name().to_string(),
+                new_name: location.node.name().to_string(),
+            });
+        }
+
+        if old_location.parent != location.parent {
+            changes.push(TreeChange::Moved {
+                id: id.clone(),
+                old_parent: old_location.parent.clone(),
+                old_index: old_location.index,
+                new_parent: location.parent.clone(),
+                new_index: location.index,
+            });
+        } else if reordered.contains(id) {
+            changes.push(TreeChange::Reordered {
+                id: id.clone(),
+                parent: location.parent.clone(),
+                old_index: old_location.index,
+                new_index: location.index,
+            });
+        }
+    }
+
+    TreeDiff { changes }
+}
+
+/// Where a node sits in a tree.
+struct Location<'a, N: OutlinerNode> {
+    node: &'a N,
+    parent: Option<N::Id>,
+    index: usize,
+}
+
+/// Node locations of one tree, by ID and in depth-first order.
+struct TreeIndex<'a, N: OutlinerNode> {
+    order: Vec<(N::Id, Location<'a, N>)>,
+    locations: HashMap<N::Id, Location<'a, N>>,
+    children: HashMap<Option<N::Id>, Vec<N::Id>>,
+}
+
+impl<N: OutlinerNode> Default for TreeIndex<'_, N> {
+    fn default() -> Self {
+        Self {
+            order: Vec::new(),
+            locations: HashMap::new(),
+            children: HashMap::new(),
+        }
+    }
+}
+
+impl<'a, N: OutlinerNode> TreeIndex<'a, N> {
+    fn collect(&mut self, nodes: &'a [N], parent: Option<N::Id>) {
+        let ids = self.children.entry(parent.clone()).or_default();
+        ids.extend(nodes.iter().map(|node| node.id()));
+
+        for (index, node) in nodes.iter().enumerate() {
+            let id = node.id();
+            let location = || Location { node, parent: parent.clone(), index };
+            self.order.push((id.clone(), location()));
+            self.locations.insert(id.clone(), location());
+            self.collect(node.children(), Some(id));
+        }
+    }
+}
+
+/// Finds nodes that kept their parent but not their order among retained siblings.
+///
+/// For each parent, the siblings present in both trees keep the longest run that
+/// is already in the right relative order; the rest are reported as reordered.
+fn reordered_ids<'a, N: OutlinerNode>(
+    old_index: &TreeIndex<'_, N>,
+    new_index: &'a TreeIndex<'_, N>,
+) -> HashSet<&'a N::Id> {
+    let mut reordered = HashSet::new();
+
+    for (parent, new_children) in &new_index.children {
+        let Some(old_children) = old_index.children.get(parent) else {
+            continue;
+        };
+        let old_positions: HashMap<&N::Id, usize> =
+            old_children.iter().enumerate().map(|(i, id)| (id, i)).collect();
+
+        // Retained siblings in new order, with their old positions
+        let retained: Vec<(&N::Id, usize)> = new_children
+            .iter()
+            .filter_map(|id| old_positions.get(id).map(|&pos| (id, pos)))
+            .collect();
+
+        let positions: Vec<usize> = retained.iter().map(|&(_, pos)| pos).collect();
+        let in_order = longest_increasing_subsequence(&positions);
+        reordered.extend(
+            retained
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| !in_order.contains(i))
+                .map(|(_, &(id, _))| id),
+        );
+    }
+
+    reordered
+}
+
+/// Returns the indices of one longest strictly increasing subsequence.
+fn longest_increasing_subsequence(values: &[usize]) -> HashSet<usize> {
+    // tails[k] is the index of the smallest tail of an increasing run of length k + 1
+    let mut tails: Vec<usize> = Vec::new();
+    let mut previous: Vec<Option<usize>> = vec![None; values.len()];
+
+    for (i, &value) in values.iter().enumerate() {
+        let k = tails.partition_point(|&t| values[t] < value);
+        previous[i] = k.checked_sub(1).map(|k| tails[k]);
+        if k == tails.len() {
+            tails.push(i);
+        } else {
+            tails[k] = i;
+        }
+    }
+
+    let mut result = HashSet::new();
+    let mut current = tails.last().copied();
+    while let Some(i) = current {
+        result.insert(i);
+        current = previous[i];
+    }
+    result
+}
+
+/// Moves nodes whose ID is in `ids` out of the tree, searching inside taken nodes too.
+fn take_nodes<N: OutlinerNode>(
+    nodes: &mut Vec<N>,
+    ids: &HashSet<&N::Id>,
+    taken: &mut HashMap<N::Id, N>,
+) {
+    for node in nodes.iter_mut() {
+        take_nodes(node.children_mut(), ids, taken);
+    }
+
+    let (matching, kept): (Vec<N>, Vec<N>) = std::mem::take(nodes)
+        .into_iter()
+        .partition(|node| ids.contains(&node.id()));
+    *nodes = kept;
+    taken.extend(matching.into_iter().map(|node| (node.id(), node)));
+}
+
+/// Inserts a node at `index` among the children of `parent` (or the roots).
+fn insert_at<N: TreeOperations>(
+    roots: &mut Vec<N>,
+    parent: Option<&N::Id>,
+    index: usize,
+    node: N,
+) -> bool {
+    let siblings = match parent {
+        None => roots,
+        Some(parent_id) => match roots.iter_mut().find_map(|root| root.find_node_mut(parent_id)) {
+            Some(parent) => parent.children_mut(),
+            None => return false,
+        },
+    };
+
+    siblings.insert(index.min(siblings.len()), node);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct TestNode {
+        id: u64,
+        name: String,
+        children: Vec<TestNode>,
+    }
+
+    impl TestNode {
+        fn new(id: u64, name: &str) -> Self {
+            Self {
+                id,
+                name: name.to_string(),
+                children: Vec::new(),
+            }
+        }
+
+        fn with_children(mut self, children: Vec<TestNode>) -> Self {
+            self.children = children;
+            self
+        }
+    }
+
+    impl OutlinerNode for TestNode {
+        type Id = u64;
+
+        fn id(&self) -> Self::Id {
+            self.id
+        }
+
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        fn is_collection(&self) -> bool {
+            true
+        }
+
+        fn children(&self) -> &[Self] {
+            &self.children
+        }
+
+        fn children_mut(&mut self) -> &mut Vec<Self> {
+            &mut self.children
+        }
+    }
+
+    impl TreeOperations for TestNode {
+        fn set_name(&mut self, name: String) -> bool {
+            self.name = name;
+            true
+        }
+    }
+
+    fn scene() -> Vec<TestNode> {
+        vec![
+            TestNode::new(1, "World").with_children(vec![
+                TestNode::new(2, "Cube"),
+                TestNode::new(3, "Sphere"),
+                TestNode::new(4, "Cone"),
+            ]),
+            TestNode::new(5, "Lights").with_children(vec![TestNode::new(6, "Sun")]),
+        ]
+    }
+
+    /// Asserts that applying the diff of `old` and `new` to `old` yields `new`.
+    fn assert_round_trip(old: &[TestNode], new: &[TestNode]) -> TreeDiff<TestNode> {
+        let changes = diff(old, new);
+        let mut patched = old.to_vec();
+        assert!(changes.apply(&mut patched));
+        assert_eq!(patched, new);
+        changes
+    }
+
+    #[test]
+    fn test_identical_trees() {
+        let changes = diff(&scene(), &scene());
+        assert!(changes.is_empty());
+        assert_eq!(changes.len(), 0);
+    }
+
+    #[test]
+    fn test_added_and_removed() {
+        let old = scene();
+        let mut new = scene();
+        new[0].children.remove(1);
+        new[1].children.push(TestNode::new(7, "Lamp").with_children(vec![TestNode::new(8, "Bulb")]));
+
+        let changes = assert_round_trip(&old, &new);
+        assert_eq!(
+            changes.changes(),
+            &[
+                TreeChange::Removed { id: 3, parent: Some(1), index: 1 },
+                TreeChange::Added { id: 7, parent: Some(5), index: 1, node: TestNode::new(7, "Lamp") },
+                TreeChange::Added { id: 8, parent: Some(7), index: 0, node: TestNode::new(8, "Bulb") },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_renamed() {
+        let old = scene();
+        let mut new = scene();
+        new[1].children[0].name = "Moon".to_string();
+
+        let changes = assert_round_trip(&old, &new);
+        assert_eq!(
+            changes.changes(),
+            &[TreeChange::Renamed { id: 6, old_name: "Sun".to_string(), new_name: "Moon".to_string() }]
+        );
+    }
+
+    #[test]
+    fn test_moved() {
+        let old = scene();
+        let mut new = scene();
+        let cube = new[0].children.remove(0);
+        new[1].children.insert(0, cube);
+
+        let changes = assert_round_trip(&old, &new);
+        assert_eq!(
+            changes.changes(),
+            &[TreeChange::Moved {
+                id: 2,
+                old_parent: Some(1),
+                old_index: 0,
+                new_parent: Some(5),
+                new_index: 0,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_moved_to_root() {
+        let old = scene();
+        let mut new = scene();
+        let sun = new[1].children.remove(0);
+        new.insert(0, sun);
+
+        let changes = assert_round_trip(&old, &new);
+        assert!(matches!(
+            changes.changes(),
+            [TreeChange::Moved { id: 6, new_parent: None, new_index: 0, .. }]
+        ));
+    }
+
+    #[test]
+    fn test_reordered_reports_minimal_set() {
+        let old = scene();
+        let mut new = scene();
+        // Move "Cone" to the front: Cube and Sphere only shift
+        let cone = new[0].children.remove(2);
+        new[0].children.insert(0, cone);
+
+        let changes = assert_round_trip(&old, &new);
+        assert_eq!(
+            changes.changes(),
+            &[TreeChange::Reordered { id: 4, parent: Some(1), old_index: 2, new_index: 0 }]
+        );
+    }
+
+    #[test]
+    fn test_insertion_does_not_report_reorder() {
+        let old = scene();
+        let mut new = scene();
+        new[0].children.insert(0, TestNode::new(9, "Plane"));
+
+        let changes = assert_round_trip(&old, &new);
+        assert_eq!(changes.len(), 1);
+        assert!(matches!(changes.changes()[0], TreeChange::Added { id: 9, .. }));
+    }
+
+    #[test]
+    fn test_nested_moves_and_renames() {
+        let old = scene();
+        // Swap the roots, move "World" under "Lights", pull "Sphere" to the root
+        // and rename it
+        let mut world = scene().remove(0);
+        let mut sphere = world.children.remove(1);
+        sphere.name = "Ball".to_string();
+        let lights = TestNode::new(5, "Lights").with_children(vec![TestNode::new(6, "Sun"), world]);
+        let new = vec![sphere, lights];
+
+        let changes = assert_round_trip(&old, &new);
+        assert!(changes.changes().iter().any(|c| matches!(c, TreeChange::Renamed { id: 3, .. })));
+        assert!(changes.changes().iter().any(|c| matches!(c, TreeChange::Moved { id: 3, new_parent: None, .. })));
+        assert!(changes.changes().iter().any(|c| matches!(c, TreeChange::Moved { id: 1, new_parent: Some(5), .. })));
+    }
+
+    #[test]
+    fn test_apply_reports_missing_nodes() {
+        let changes = diff(&scene(), &[]);
+        let mut unrelated = vec![TestNode::new(42, "Other")];
+        assert!(!changes.apply(&mut unrelated));
+        assert_eq!(unrelated, vec![TestNode::new(42, "Other")]);
+    }
+
+    #[test]
+    fn test_change_id() {
+        let change: TreeChange<TestNode> = TreeChange::Removed { id: 3, parent: None, index: 0 };
+        assert_eq!(change.id(), &3);
+    }
+
+    #[test]
+    fn test_longest_increasing_subsequence() {
+        let lis = longest_increasing_subsequence(&[2, 0, 1, 3]);
+        assert_eq!(lis, HashSet::from([1, 2, 3]));
+        assert!(longest_increasing_subsequence(&[]).is_empty());
+    }
+}
@@ -1,8 +1,8 @@
 //! Tree manipulation operations for outliner nodes.
 //!
 //! This module provides the [`TreeOperations`] trait which offers default implementations
-//! for common tree manipulation operations like renaming, removing, and inserting nodes.
-//! These operations are essential for implementing drag-drop and editing functionality.
+//! for common tree manipulation operations like renaming, removing, inserting, and
+//! duplicating nodes. These operations are essential for implementing drag-drop and editing functionality.
 //!
 //! # Examples
 //!
@@ -33,6 +33,77 @@
 //! ```
 
 use crate::traits::{DropPosition, OutlinerNode};
+use std::collections::HashSet;
+
+/// Options controlling how [`TreeOperations::duplicate_node`] builds the copy.
+///
+/// # Examples
+///
+/// ```
+/// use egui_arbor::tree_ops::DuplicateOptions;
+///
+/// let options = DuplicateOptions::default().with_numbered_names(true);
+/// assert!(options.numbered_names);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DuplicateOptions {
+    /// Whether copied nodes get Blender-style numbered names ("Cube" → "Cube.001").
+    ///
+    /// Numbers are chosen so the new name is unique within the tree the
+    /// duplicate is made in. Requires [`TreeOperations::set_name`] to be implemented.
+    ///
+    /// Default: `false`
+    pub numbered_names: bool,
+}
+
+impl DuplicateOptions {
+    /// Set whether copied nodes get numbered names.
+    ///
+    /// # Arguments
+    /// * `numbered` - `true` to suffix copies with `.001`, `.002`, ...
+    pub fn with_numbered_names(mut self, numbered: bool) -> Self {
+        self.numbered_names = numbered;
+        self
+    }
+}
+
+/// Returns the next free Blender-style numbered variant of `name`.
+///
+/// An existing numeric suffix (`.001`, `.012`, ...) is stripped first, so
+/// duplicating "Cube.001" yields "Cube.002" rather than "Cube.001.001".
+///
+/// # Arguments
+///
+/// * `name` - The name being copied
+/// * `is_taken` - Returns `true` if a candidate name is already in use
+///
+/// # Examples
+///
+/// ```
+/// use egui_arbor::tree_ops::numbered_name;
+///
+/// assert_eq!(numbered_name("Cube", |_| false), "Cube.001");
+/// assert_eq!(numbered_name("Cube.001", |n| n == "Cube.001"), "Cube.002");
+/// ```
+pub fn numbered_name(name: &str, is_taken: impl Fn(&str) -> bool) -> String {
+    let base = match name.rsplit_once('.') {
+        Some((base, suffix))
+            if suffix.len() >= 3 && suffix.chars().all(|c| c.is_ascii_digit()) =>
+        {
+            base
+        }
+        _ => name,
+    };
+
+    let mut number = 1;
+    loop {
+        let candidate = format!("{}.{:03}", base, number);
+        if !is_taken(&candidate) {
+            return candidate;
+        }
+        number += 1;
+    }
+}
 
 /// Trait providing tree manipulation operations for outliner nodes.
 ///
@@ -40,6 +111,8 @@ use crate::traits::{DropPosition, OutlinerNode};
 /// - **Renaming**: Find and update a node's name by ID
 /// - **Removing**: Extract a node from the tree by ID
 /// - **Inserting**: Place a node at a specific position relative to a target
+/// - **Duplicating**: Deep-clone a subtree with fresh IDs next to the original
+/// - **Bulk removal**: Remove a set of nodes in a single traversal
 ///
 /// All methods use recursive traversal to locate nodes within the tree hierarchy.
 ///
@@ -65,11 +138,36 @@ use crate::traits::{DropPosition, OutlinerNode};
 /// }
 /// ```
 pub trait TreeOperations: OutlinerNode + Sized + Clone {
+    /// Replaces this node's ID.
+    ///
+    /// [`OutlinerNode`] only exposes IDs read-only, so operations that create new
+    /// nodes (such as [`duplicate_node`](Self::duplicate_node)) rely on this hook.
+    /// The default implementation does nothing and returns `false`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the ID was updated, `false` if this node type does not support it.
+    fn set_id(&mut self, _id: Self::Id) -> bool {
+        false
+    }
+
+    /// Replaces this node's display name.
+    ///
+    /// Used by [`rename_node`](Self::rename_node) and numbered duplicates.
+    /// The default implementation does nothing and returns `false`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the name was updated, `false` if this node type does not support it.
+    fn set_name(&mut self, _name: String) -> bool {
+        false
+    }
+
     /// Finds a node by ID and updates its name.
     ///
     /// This method recursively searches the tree starting from this node,
     /// looking for a node with the specified ID. When found, it updates
-    /// the node's name.
+    /// the node's name through [`set_name`](Self::set_name).
     ///
     /// # Arguments
     ///
@@ -90,10 +188,7 @@ pub trait TreeOperations: OutlinerNode + Sized + Clone {
     fn rename_node(&mut self, id: &Self::Id, new_name: String) -> bool {
         // Check if this is the target node
         if self.id() == *id {
-            // We can't directly modify the name through the trait,
-            // so we need to work with children
-            // This is a limitation - users may need to override this method
-            return false;
+            return self.set_name(new_name);
         }
 
         // Search in children
@@ -147,6 +242,32 @@ pub trait TreeOperations: OutlinerNode + Sized + Clone {
         None
     }
 
+    /// Removes every node whose ID is in `ids` in a single traversal.
+    ///
+    /// This method searches this node's descendants; descendants of a removed
+    /// node are removed along with it and are not searched further.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the nodes to remove
+    ///
+    /// # Returns
+    ///
+    /// The removed nodes. IDs that were not found are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let removed = root.remove_nodes(response.delete_requested());
+    /// println!("Deleted {} nodes", removed.len());
+    /// ```
+    fn remove_nodes(&mut self, ids: &[Self::Id]) -> Vec<Self> {
+        let ids: HashSet<&Self::Id> = ids.iter().collect();
+        let mut removed = Vec::new();
+        remove_matching(self.children_mut(), &ids, &mut removed);
+        removed
+    }
+
     /// Inserts a node at a specific position relative to a target node.
     ///
     /// This method recursively searches for the target node and inserts the new node
@@ -163,7 +284,9 @@ pub trait TreeOperations: OutlinerNode + Sized + Clone {
     ///
     /// # Returns
     ///
-    /// `true` if the node was successfully inserted, `false` otherwise.
+    /// `true` if the node was successfully inserted, `false` otherwise. Placing
+    /// nodes at the root level, including [`DropPosition::End`], needs the list
+    /// of roots; use [`insert_into_roots`] for that.
     ///
     /// # Examples
     ///
@@ -209,6 +332,8 @@ pub trait TreeOperations: OutlinerNode + Sized + Clone {
                             return true;
                         }
                     }
+                    // Appending at the root level needs the root list
+                    DropPosition::End => {}
                 }
                 return false;
             }
@@ -222,6 +347,49 @@ pub trait TreeOperations: OutlinerNode + Sized + Clone {
         false
     }
 
+    /// Deep-clones a node and its subtree, inserting the copy right after the original.
+    ///
+    /// Every node in the copy receives a fresh ID from `next_id`, which is called
+    /// with the original node the new ID is for. Like [`remove_node`](Self::remove_node),
+    /// this searches this node's descendants; the node itself cannot be duplicated
+    /// because it has no parent to insert the copy into.
+    ///
+    /// Requires [`set_id`](Self::set_id) to be implemented; with the default
+    /// implementation nothing is inserted and `None` is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the node to duplicate
+    /// * `next_id` - Generator producing a fresh, unused ID for each copied node
+    /// * `options` - How the copy should be named
+    ///
+    /// # Returns
+    ///
+    /// The ID of the new copy if the node was found and duplicated, `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let mut counter = 100;
+    /// let options = DuplicateOptions::default().with_numbered_names(true);
+    /// let copy_id = root.duplicate_node(&cube_id, &mut |_| { counter += 1; counter }, &options);
+    /// ```
+    fn duplicate_node<F>(
+        &mut self,
+        id: &Self::Id,
+        next_id: &mut F,
+        options: &DuplicateOptions,
+    ) -> Option<Self::Id>
+    where
+        F: FnMut(&Self) -> Self::Id,
+    {
+        let mut taken = HashSet::new();
+        if options.numbered_names {
+            collect_names(self, &mut taken);
+        }
+        duplicate_in_children(self, id, next_id, options, &mut taken)
+    }
+
     /// Finds a node by ID in the tree.
     ///
     /// This is a helper method that recursively searches for a node with the given ID.
@@ -274,6 +442,245 @@ pub trait TreeOperations: OutlinerNode + Sized + Clone {
     }
 }
 
+/// Finds a node by ID among a list of root nodes and their descendants.
+///
+/// Outliners display a `Vec` of root nodes; this is the root-level counterpart
+/// of [`TreeOperations::find_node`].
+///
+/// # Examples
+///
+/// ```ignore
+/// if let Some(node) = find_in_roots(&tree, &id) {
+///     println!("Found: {}", node.name());
+/// }
+/// ```
+pub fn find_in_roots<'a, N: TreeOperations>(roots: &'a [N], id: &N::Id) -> Option<&'a N> {
+    roots.iter().find_map(|root| root.find_node(id))
+}
+
+/// Removes a node by ID from a list of root nodes and their descendants.
+///
+/// Unlike [`TreeOperations::remove_node`], this can also remove the root nodes
+/// themselves.
+///
+/// # Returns
+///
+/// `Some(node)` if the node was found and removed, `None` otherwise.
+pub fn remove_from_roots<N: TreeOperations>(roots: &mut Vec<N>, id: &N::Id) -> Option<N> {
+    if let Some(index) = roots.iter().position(|root| root.id() == *id) {
+        return Some(roots.remove(index));
+    }
+
+    roots.iter_mut().find_map(|root| root.remove_node(id))
+}
+
+/// Removes every node whose ID is in `ids` from a list of root nodes in a single traversal.
+///
+/// This is the root-level counterpart of [`TreeOperations::remove_nodes`] and can
+/// also remove the root nodes themselves.
+///
+/// # Returns
+///
+/// The removed nodes. IDs that were not found are ignored.
+pub fn remove_all_from_roots<N: TreeOperations>(roots: &mut Vec<N>, ids: &[N::Id]) -> Vec<N> {
+    let ids: HashSet<&N::Id> = ids.iter().collect();
+    let mut removed = Vec::new();
+    remove_matching(roots, &ids, &mut removed);
+    removed
+}
+
+/// Inserts a node relative to a target within a list of root nodes.
+///
+/// Unlike [`TreeOperations::insert_node`], this supports
+/// [`DropPosition::Before`] and [`DropPosition::After`] root-level targets, and
+/// [`DropPosition::End`], which appends to `roots` and ignores `target_id`.
+///
+/// # Returns
+///
+/// `true` if the node was inserted, `false` if the target was not found or
+/// cannot accept the node.
+pub fn insert_into_roots<N: TreeOperations>(
+    roots: &mut Vec<N>,
+    target_id: &N::Id,
+    node: N,
+    position: DropPosition,
+) -> bool {
+    let index = roots.iter().position(|root| root.id() == *target_id);
+    match (position, index) {
+        (DropPosition::End, _) => roots.push(node),
+        (DropPosition::Before, Some(index)) => roots.insert(index, node),
+        (DropPosition::After, Some(index)) => roots.insert(index + 1, node),
+        (DropPosition::Inside, Some(index)) => {
+            if !roots[index].is_collection() {
+                return false;
+            }
+            roots[index].children_mut().push(node);
+        }
+        (_, None) => {
+            return roots
+                .iter_mut()
+                .any(|root| root.insert_node(target_id, node.clone(), position));
+        }
+    }
+    true
+}
+
+/// Places copies of the given nodes (with their subtrees) at a drop location.
+///
+/// This is the copy path for drops with
+/// [`DropOperation::Copy`](crate::DropOperation::Copy): the originals stay in
+/// place and every copied node receives a fresh ID from `next_id`. Copies keep
+/// the order of `ids`, the first one being placed at `target`/`position`; with
+/// no target (a [`DropPosition::End`] drop) they are appended at the root level.
+///
+/// # Returns
+///
+/// The IDs of the inserted copies. Nodes that are not found or cannot be
+/// placed (unknown target, [`DropPosition::Inside`] a non-collection, or a node
+/// type without [`TreeOperations::set_id`]) are skipped.
+///
+/// # Examples
+///
+/// ```ignore
+/// if let Some(drop) = response.drop_event()
+///     && drop.operation == DropOperation::Copy
+/// {
+///     tree_ops::copy_into_roots(&mut roots, response.dragging_nodes(), drop.target.as_ref(), drop.position, &mut |_| {
+///         next_id += 1;
+///         next_id
+///     });
+/// }
+/// ```
+pub fn copy_into_roots<N, F>(
+    roots: &mut Vec<N>,
+    ids: &[N::Id],
+    target_id: Option<&N::Id>,
+    position: DropPosition,
+    next_id: &mut F,
+) -> Vec<N::Id>
+where
+    N: TreeOperations,
+    F: FnMut(&N) -> N::Id,
+{
+    let originals: Vec<N> = ids
+        .iter()
+        .filter_map(|id| find_in_roots(roots, id).cloned())
+        .collect();
+
+    let mut copied: Vec<N::Id> = Vec::new();
+    let mut taken = HashSet::new();
+    for mut copy in originals {
+        if !reassign_copy(&mut copy, next_id, &DuplicateOptions::default(), &mut taken) {
+            continue;
+        }
+
+        let copy_id = copy.id();
+        // Keep the order of `ids` by placing each copy after the previous one
+        let inserted = match (copied.last(), target_id) {
+            (Some(previous), _) if position != DropPosition::Inside => {
+                insert_into_roots(roots, previous, copy, DropPosition::After)
+            }
+            (_, Some(target_id)) => insert_into_roots(roots, target_id, copy, position),
+            (_, None) => {
+                roots.push(copy);
+                true
+            }
+        };
+        if inserted {
+            copied.push(copy_id);
+        }
+    }
+
+    copied
+}
+
+/// Moves nodes whose ID is in `ids` from `nodes` (at any depth) into `removed`.
+fn remove_matching<N: OutlinerNode>(
+    nodes: &mut Vec<N>,
+    ids: &HashSet<&N::Id>,
+    removed: &mut Vec<N>,
+) {
+    let (matching, kept): (Vec<N>, Vec<N>) = std::mem::take(nodes)
+        .into_iter()
+        .partition(|node| ids.contains(&node.id()));
+    *nodes = kept;
+    removed.extend(matching);
+
+    for node in nodes.iter_mut() {
+        remove_matching(node.children_mut(), ids, removed);
+    }
+}
+
+/// Collects the names of a node and all of its descendants.
+fn collect_names<N: OutlinerNode>(node: &N, names: &mut HashSet<String>) {
+    names.insert(node.name().to_string());
+    for child in node.children() {
+        collect_names(child, names);
+    }
+}
+
+/// Searches `node`'s descendants for `id` and inserts a re-identified copy after it.
+fn duplicate_in_children<N, F>(
+    node: &mut N,
+    id: &N::Id,
+    next_id: &mut F,
+    options: &DuplicateOptions,
+    taken: &mut HashSet<String>,
+) -> Option<N::Id>
+where
+    N: TreeOperations,
+    F: FnMut(&N) -> N::Id,
+{
+    let children = node.children_mut();
+
+    if let Some(index) = children.iter().position(|child| child.id() == *id) {
+        let mut copy = children[index].clone();
+        if !reassign_copy(&mut copy, next_id, options, taken) {
+            return None;
+        }
+        let copy_id = copy.id();
+        children.insert(index + 1, copy);
+        return Some(copy_id);
+    }
+
+    for child in children.iter_mut() {
+        if let Some(copy_id) = duplicate_in_children(child, id, next_id, options, taken) {
+            return Some(copy_id);
+        }
+    }
+
+    None
+}
+
+/// Gives a freshly cloned subtree new IDs and, optionally, numbered names.
+///
+/// Returns `false` if the node type does not support [`TreeOperations::set_id`].
+pub(crate) fn reassign_copy<N, F>(
+    node: &mut N,
+    next_id: &mut F,
+    options: &DuplicateOptions,
+    taken: &mut HashSet<String>,
+) -> bool
+where
+    N: TreeOperations,
+    F: FnMut(&N) -> N::Id,
+{
+    let fresh_id = next_id(node);
+    if !node.set_id(fresh_id) {
+        return false;
+    }
+
+    if options.numbered_names {
+        let name = numbered_name(node.name(), |candidate| taken.contains(candidate));
+        taken.insert(name.clone());
+        node.set_name(name);
+    }
+
+    node.children_mut()
+        .iter_mut()
+        .all(|child| reassign_copy(child, next_id, options, taken))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -339,7 +746,49 @@ mod tests {
         }
     }
 
-    impl TreeOperations for TestNode {}
+    impl TreeOperations for TestNode {
+        fn set_id(&mut self, id: u64) -> bool {
+            self.id = id;
+            true
+        }
+
+        fn set_name(&mut self, name: String) -> bool {
+            self.name = name;
+            true
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    struct ReadOnlyNode {
+        id: u64,
+        children: Vec<ReadOnlyNode>,
+    }
+
+    impl OutlinerNode for ReadOnlyNode {
+        type Id = u64;
+
+        fn id(&self) -> Self::Id {
+            self.id
+        }
+
+        fn name(&self) -> &str {
+            "read-only"
+        }
+
+        fn is_collection(&self) -> bool {
+            !self.children.is_empty()
+        }
+
+        fn children(&self) -> &[Self] {
+            &self.children
+        }
+
+        fn children_mut(&mut self) -> &mut Vec<Self> {
+            &mut self.children
+        }
+    }
+
+    impl TreeOperations for ReadOnlyNode {}
 
     #[test]
     fn test_remove_node_direct_child() {
@@ -466,4 +915,191 @@ mod tests {
 
         assert_eq!(root.children[0].name, "modified");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_rename_node_uses_set_name() {
+        let mut root = TestNode::new(1, "root", true).with_children(vec![
+            TestNode::new(2, "child1", false),
+        ]);
+
+        assert!(root.rename_node(&2, "renamed".to_string()));
+        assert_eq!(root.children[0].name, "renamed");
+    }
+
+    #[test]
+    fn test_roots_helpers() {
+        let mut roots = vec![
+            TestNode::new(1, "a", true).with_children(vec![TestNode::new(2, "b", false)]),
+            TestNode::new(3, "c", false),
+        ];
+
+        assert_eq!(find_in_roots(&roots, &2).map(|n| n.id), Some(2));
+        assert!(find_in_roots(&roots, &9).is_none());
+
+        // Root-level Before/After insertion
+        assert!(insert_into_roots(&mut roots, &1, TestNode::new(4, "d", false), DropPosition::Before));
+        assert!(insert_into_roots(&mut roots, &3, TestNode::new(5, "e", false), DropPosition::After));
+        assert_eq!(roots.iter().map(|n| n.id).collect::<Vec<_>>(), vec![4, 1, 3, 5]);
+
+        // Nested insertion and invalid Inside
+        assert!(insert_into_roots(&mut roots, &2, TestNode::new(6, "f", false), DropPosition::After));
+        assert!(!insert_into_roots(&mut roots, &3, TestNode::new(7, "g", false), DropPosition::Inside));
+        assert_eq!(roots[1].children.len(), 2);
+
+        // Root and nested removal
+        assert_eq!(remove_from_roots(&mut roots, &3).map(|n| n.id), Some(3));
+        assert_eq!(remove_from_roots(&mut roots, &6).map(|n| n.id), Some(6));
+        assert!(remove_from_roots(&mut roots, &9).is_none());
+        assert_eq!(roots.len(), 3);
+
+        // End appends at the root level, even if the target is gone
+        assert!(insert_into_roots(&mut roots, &3, TestNode::new(8, "h", false), DropPosition::End));
+        assert_eq!(roots.last().map(|n| n.id), Some(8));
+        assert!(!roots[1].insert_node(&2, TestNode::new(9, "i", false), DropPosition::End));
+    }
+
+    #[test]
+    fn test_remove_nodes_single_pass() {
+        let mut root = TestNode::new(1, "root", true).with_children(vec![
+            TestNode::new(2, "a", true).with_children(vec![
+                TestNode::new(3, "b", false),
+                TestNode::new(4, "c", false),
+            ]),
+            TestNode::new(5, "d", false),
+            TestNode::new(6, "e", false),
+        ]);
+
+        // 3 is removed together with its parent 2 and only reported once
+        let removed = root.remove_nodes(&[2, 3, 6, 999]);
+        let mut removed_ids: Vec<u64> = removed.iter().map(|n| n.id).collect();
+        removed_ids.sort();
+        assert_eq!(removed_ids, vec![2, 6]);
+        assert_eq!(root.children.len(), 1);
+        assert_eq!(root.children[0].id, 5);
+    }
+
+    #[test]
+    fn test_remove_all_from_roots() {
+        let mut roots = vec![
+            TestNode::new(1, "a", true).with_children(vec![TestNode::new(2, "b", false)]),
+            TestNode::new(3, "c", false),
+        ];
+
+        let removed = remove_all_from_roots(&mut roots, &[2, 3]);
+        assert_eq!(removed.len(), 2);
+        assert_eq!(roots.len(), 1);
+        assert!(roots[0].children.is_empty());
+    }
+
+    #[test]
+    fn test_copy_into_roots() {
+        let mut roots = vec![
+            TestNode::new(1, "Group", true).with_children(vec![TestNode::new(2, "Cube", false)]),
+            TestNode::new(3, "Light", false),
+            TestNode::new(4, "Props", true),
+        ];
+        let mut next = 100;
+        let mut next_id = |_: &TestNode| {
+            next += 1;
+            next
+        };
+
+        // Copies keep their order and the originals stay in place
+        let copied = copy_into_roots(&mut roots, &[1, 3], Some(&4), DropPosition::Inside, &mut next_id);
+        assert_eq!(copied, vec![101, 103]);
+        assert_eq!(roots.len(), 3);
+        let props: Vec<u64> = roots[2].children.iter().map(|node| node.id).collect();
+        assert_eq!(props, vec![101, 103]);
+        assert_eq!(roots[2].children[0].children[0].id, 102);
+        assert_eq!(roots[2].children[0].children[0].name, "Cube");
+
+        // Root-level Before/After, and unknown IDs are skipped
+        let copied = copy_into_roots(&mut roots, &[3, 999], Some(&1), DropPosition::Before, &mut next_id);
+        assert_eq!(copied, vec![104]);
+        assert_eq!(roots[0].id, 104);
+
+        // Several copies after one target keep their order instead of reversing
+        let copied = copy_into_roots(&mut roots, &[1, 3], Some(&1), DropPosition::After, &mut next_id);
+        assert_eq!(copied, vec![105, 107]);
+        assert_eq!(roots.iter().map(|node| node.id).collect::<Vec<_>>(), vec![104, 1, 105, 107, 3, 4]);
+
+        // Copying into a non-collection fails
+        assert!(copy_into_roots(&mut roots, &[1], Some(&3), DropPosition::Inside, &mut next_id).is_empty());
+
+        // Without a target, copies are appended at the root level
+        let copied = copy_into_roots(&mut roots, &[3, 4], None, DropPosition::End, &mut next_id);
+        assert_eq!(copied.len(), 2);
+        assert_eq!(roots[roots.len() - 2..].iter().map(|node| node.id).collect::<Vec<_>>(), copied);
+    }
+
+    #[test]
+    fn test_numbered_name() {
+        assert_eq!(numbered_name("Cube", |_| false), "Cube.001");
+        assert_eq!(numbered_name("Cube.001", |n| n == "Cube.001"), "Cube.002");
+        assert_eq!(numbered_name("v1.2", |_| false), "v1.2.001");
+        assert_eq!(numbered_name("Cube", |n| n == "Cube.001" || n == "Cube.002"), "Cube.003");
+    }
+
+    #[test]
+    fn test_duplicate_node_subtree() {
+        let mut root = TestNode::new(1, "root", true).with_children(vec![
+            TestNode::new(2, "Group", true).with_children(vec![
+                TestNode::new(3, "Cube", false),
+            ]),
+            TestNode::new(4, "Light", false),
+        ]);
+
+        let mut next = 100;
+        let copy_id = root.duplicate_node(
+            &2,
+            &mut |_| {
+                next += 1;
+                next
+            },
+            &DuplicateOptions::default(),
+        );
+
+        assert_eq!(copy_id, Some(101));
+        assert_eq!(root.children.len(), 3);
+        assert_eq!(root.children[1].id, 101);
+        assert_eq!(root.children[1].name, "Group");
+        assert_eq!(root.children[1].children[0].id, 102);
+        assert_eq!(root.children[2].id, 4);
+        // The original is untouched
+        assert_eq!(root.children[0].children[0].id, 3);
+    }
+
+    #[test]
+    fn test_duplicate_node_numbered_names() {
+        let mut root = TestNode::new(1, "root", true).with_children(vec![
+            TestNode::new(2, "Cube", false),
+            TestNode::new(3, "Cube.001", false),
+        ]);
+
+        let options = DuplicateOptions::default().with_numbered_names(true);
+        let copy_id = root.duplicate_node(&3, &mut |node| node.id + 10, &options);
+
+        assert_eq!(copy_id, Some(13));
+        assert_eq!(root.children[2].name, "Cube.002");
+    }
+
+    #[test]
+    fn test_duplicate_node_not_found() {
+        let mut root = TestNode::new(1, "root", true);
+        let copy_id = root.duplicate_node(&999, &mut |_| 5, &DuplicateOptions::default());
+        assert!(copy_id.is_none());
+        assert!(root.children.is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_node_without_set_id() {
+        let mut root = ReadOnlyNode {
+            id: 1,
+            children: vec![ReadOnlyNode { id: 2, children: vec![] }],
+        };
+
+        let copy_id = root.duplicate_node(&2, &mut |_| 3, &DuplicateOptions::default());
+        assert!(copy_id.is_none());
+        assert_eq!(root.children.len(), 1);
+    }
+}